@@ -0,0 +1,166 @@
+//! Derive macro for [`bevy_mod_component_mirror`].
+//!
+//! Use it through the `derive` feature of `bevy_mod_component_mirror`,
+//! see the documentation of `bevy_mod_component_mirror::Mirror`.
+//!
+//! [`bevy_mod_component_mirror`]: https://docs.rs/bevy_mod_component_mirror
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse::Parser, parse_macro_input, punctuated::Punctuated, Data, DeriveInput, Error, Field,
+    Fields, LitStr, Member, Token, Type,
+};
+
+/// Where a field of the mirror is read from and written to in the source.
+enum Access {
+    /// `#[mirror(field = "a.b")]`, or the field with the same name by default.
+    Field(Vec<Member>),
+    /// `#[mirror(get = "a.get", set = "a.set")]`.
+    Method { get: Vec<Member>, set: Vec<Member> },
+    /// `#[mirror(skip)]`, use `Default` and never write it.
+    Skip,
+}
+
+/// Parse a path such as `"inner.radius"` or `"0.length"`.
+fn parse_path(lit: &LitStr) -> syn::Result<Vec<Member>> {
+    let parser = Punctuated::<Member, Token![.]>::parse_separated_nonempty;
+    Ok(parser
+        .parse_str(&lit.value())
+        .map_err(|err| Error::new(lit.span(), err))?
+        .into_iter()
+        .collect())
+}
+fn parse_method(lit: &LitStr) -> syn::Result<Vec<Member>> {
+    let path = parse_path(lit)?;
+    match path.last() {
+        Some(Member::Named(_)) => Ok(path),
+        _ => Err(Error::new(lit.span(), "expected a method name")),
+    }
+}
+
+fn field_access(member: &Member, field: &Field) -> syn::Result<Access> {
+    let mut path = None;
+    let mut get = None;
+    let mut set = None;
+    let mut skip = false;
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("mirror")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skip = true;
+            } else if meta.path.is_ident("field") {
+                path = Some(parse_path(&meta.value()?.parse()?)?);
+            } else if meta.path.is_ident("get") {
+                get = Some(parse_method(&meta.value()?.parse()?)?);
+            } else if meta.path.is_ident("set") {
+                set = Some(parse_method(&meta.value()?.parse()?)?);
+            } else {
+                return Err(meta.error("expected `field`, `get`, `set` or `skip`"));
+            }
+            Ok(())
+        })?;
+    }
+    let conflict = |msg| Err(Error::new_spanned(field, msg));
+    match (skip, path, get, set) {
+        (true, None, None, None) => Ok(Access::Skip),
+        (true, ..) => conflict("`skip` can't be used with other `mirror` attributes"),
+        (false, Some(path), None, None) => Ok(Access::Field(path)),
+        (false, None, Some(get), Some(set)) => Ok(Access::Method { get, set }),
+        (false, None, None, None) => Ok(Access::Field(vec![member.clone()])),
+        (false, Some(_), ..) => conflict("`field` can't be used with `get` or `set`"),
+        (false, None, ..) => conflict("`get` and `set` must be used together"),
+    }
+}
+
+fn source_type(input: &DeriveInput) -> syn::Result<Type> {
+    let mut attrs = input.attrs.iter().filter(|a| a.path().is_ident("mirror"));
+    let missing = "expected the mirrored type, as in `#[mirror(Foreign)]`";
+    let Some(attr) = attrs.next() else {
+        return Err(Error::new_spanned(&input.ident, missing));
+    };
+    if let Some(extra) = attrs.next() {
+        return Err(Error::new_spanned(extra, "duplicate `mirror` attribute"));
+    }
+    attr.parse_args()
+}
+
+fn derive(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let source = source_type(&input)?;
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`Mirror` can only be derived on structs",
+        ));
+    };
+    let members: Vec<Member> = match &data.fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|f| Member::Named(f.ident.clone().unwrap()))
+            .collect(),
+        Fields::Unnamed(fields) => (0..fields.unnamed.len()).map(Member::from).collect(),
+        Fields::Unit => Vec::new(),
+    };
+    let mut reads = Vec::new();
+    let mut writes = Vec::new();
+    for (member, field) in members.iter().zip(&data.fields) {
+        let own = quote!(::core::convert::Into::into(::core::clone::Clone::clone(&self.#member)));
+        match field_access(member, field)? {
+            Access::Field(path) => {
+                reads.push(quote!(::core::convert::Into::into(
+                    ::core::clone::Clone::clone(&value.#(#path).*)
+                )));
+                writes.push(Some(quote!(value.#(#path).* = #own;)));
+            }
+            Access::Method { get, set } => {
+                reads.push(quote!(::core::convert::Into::into(value.#(#get).*())));
+                writes.push(Some(quote!(value.#(#set).*(#own);)));
+            }
+            Access::Skip => {
+                reads.push(quote!(::core::default::Default::default()));
+                writes.push(None);
+            }
+        }
+    }
+    let diff_names = members.iter().map(|member| match member {
+        Member::Named(ident) => ident.to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
+    });
+    let diff_writes = writes.iter().zip(diff_names).filter_map(|(write, name)| {
+        let write = write.as_ref()?;
+        Some(quote!(if diff.is_changed(#name) { #write }))
+    });
+    let writes = writes.iter().flatten();
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let krate = quote!(::bevy_mod_component_mirror);
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<&#source> for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn from(value: &#source) -> Self {
+                Self { #( #members: #reads, )* }
+            }
+        }
+        impl #impl_generics #krate::Mirror<#source> for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn apply(&self, value: &mut #source) {
+                #( #writes )*
+            }
+            #[allow(unused_variables)]
+            fn apply_diff(&self, value: &mut #source, diff: &#krate::MirrorDiff) {
+                #( #diff_writes )*
+            }
+        }
+    })
+}
+
+/// Implement `From<&T>` and `Mirror<T>`, see `bevy_mod_component_mirror::Mirror`.
+#[proc_macro_derive(Mirror, attributes(mirror))]
+pub fn derive_mirror(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,319 @@
+use std::{any::type_name, collections::VecDeque, fmt};
+
+use bevy::{
+    ecs::{
+        component::Tick,
+        event::ManualEventReader,
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+        system::{StaticSystemParam, SystemChangeTick, SystemState},
+    },
+    prelude::*,
+};
+
+use crate::mirror::{apply_mirror, MirrorSnapshots};
+use crate::{ContextMirror, MirrorError, MirrorSystems, TryMirror};
+
+/// Set the mirror on `entity` (`None` for resources) to the given value,
+/// and apply it to the mirrored value. `false` if the mirror doesn't exist anymore.
+type Restore = fn(&mut World, Option<Entity>, &dyn Reflect) -> bool;
+
+/// An edit of a mirror, applied to the component or resource it mirrors.
+///
+/// See [`MirrorHistory`].
+pub struct MirrorEdit {
+    /// The entity with the mirror, `None` for resources.
+    pub entity: Option<Entity>,
+    /// The type name of the mirrored component.
+    pub source: &'static str,
+    /// The type name of the mirror component.
+    pub mirror: &'static str,
+    /// The value of the mirror before the edit.
+    pub before: Box<dyn Reflect>,
+    /// The value of the mirror after the edit.
+    pub after: Box<dyn Reflect>,
+    restore: Restore,
+    /// When the edit was last extended, see [`MirrorHistory::push`].
+    tick: Tick,
+}
+impl MirrorEdit {
+    pub(crate) fn component<T, U>(entity: Entity, before: U, after: &U) -> Self
+    where
+        T: Component,
+        U: ContextMirror<T> + Component + Reflect,
+    {
+        Self::new::<T, U>(Some(entity), before, after, restore_component::<T, U>)
+    }
+    pub(crate) fn resource<T, U>(before: U, after: &U) -> Self
+    where
+        T: Resource,
+        U: TryMirror<T> + Resource + Reflect,
+    {
+        Self::new::<T, U>(None, before, after, restore_resource::<T, U>)
+    }
+    fn new<T, U: Reflect>(entity: Option<Entity>, before: U, after: &U, restore: Restore) -> Self {
+        Self {
+            entity,
+            source: type_name::<T>(),
+            mirror: type_name::<U>(),
+            before: Box::new(before),
+            after: after.clone_value(),
+            restore,
+            tick: Tick::new(0),
+        }
+    }
+}
+impl fmt::Debug for MirrorEdit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MirrorEdit")
+            .field("entity", &self.entity)
+            .field("source", &self.source)
+            .field("mirror", &self.mirror)
+            .field("before", &self.before)
+            .field("after", &self.after)
+            .finish_non_exhaustive()
+    }
+}
+
+fn restore_component<T, U>(world: &mut World, entity: Option<Entity>, value: &dyn Reflect) -> bool
+where
+    T: Component,
+    U: ContextMirror<T> + Component + Reflect,
+{
+    let Some(entity) = entity else {
+        return false;
+    };
+    let mut state =
+        SystemState::<(Query<(&mut T, &mut U)>, StaticSystemParam<U::Param>)>::new(world);
+    let result = {
+        let (mut query, param) = state.get_mut(world);
+        let Ok((source, mut mirror)) = query.get_mut(entity) else {
+            return false;
+        };
+        mirror.apply(value);
+        apply_mirror(&*mirror, None, source, &param)
+    };
+    state.apply(world);
+    match result {
+        // The mirror is now synced with its source, this is not a new edit.
+        Ok(_) => {
+            if let Some(mut snapshots) = world.get_resource_mut::<MirrorSnapshots<T, U>>() {
+                snapshots.refresh(entity, value);
+            }
+        }
+        Err(error) => world.send_event(MirrorError::new::<T, U>(Some(entity), &error)),
+    }
+    true
+}
+fn restore_resource<T, U>(world: &mut World, _: Option<Entity>, value: &dyn Reflect) -> bool
+where
+    T: Resource,
+    U: TryMirror<T> + Resource + Reflect,
+{
+    if !world.contains_resource::<T>() || !world.contains_resource::<U>() {
+        return false;
+    }
+    world.resource_scope(|world, mut mirror: Mut<U>| {
+        mirror.apply(value);
+        if let Err(error) = apply_mirror(&*mirror, None, world.resource_mut::<T>(), &()) {
+            world.send_event(MirrorError::new::<T, U>(None, &error));
+        }
+    });
+    true
+}
+
+/// The edits of mirrors applied to the values they mirror, added
+/// by [`MirrorHistoryPlugin`].
+///
+/// Only edits of a mirror are recorded, not the updates of a mirror
+/// following a change to the value it mirrors.
+///
+/// A mirror edited over consecutive updates, such as while dragging
+/// a slider in an inspector, is recorded as a single edit.
+#[derive(Resource, Debug)]
+pub struct MirrorHistory {
+    undo: VecDeque<MirrorEdit>,
+    redo: Vec<MirrorEdit>,
+    capacity: usize,
+}
+impl MirrorHistory {
+    fn new(capacity: usize) -> Self {
+        Self {
+            undo: VecDeque::with_capacity(capacity),
+            redo: Vec::new(),
+            capacity,
+        }
+    }
+    /// Whether there is an edit to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+    /// Whether there is an undone edit to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+    /// The edits [`MirrorUndo`] would undo, most recent first.
+    pub fn undos(&self) -> impl Iterator<Item = &MirrorEdit> {
+        self.undo.iter().rev()
+    }
+    /// The edits [`MirrorRedo`] would redo, most recently undone first.
+    pub fn redos(&self) -> impl Iterator<Item = &MirrorEdit> {
+        self.redo.iter().rev()
+    }
+    /// Forget all edits.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+    /// Record a new edit, this drops the edits that could be redone.
+    ///
+    /// If the same mirror was already edited during the previous run of
+    /// the recording system, `edit` continues that edit instead: it keeps
+    /// its `before` value, and takes the `after` value of `edit`.
+    pub(crate) fn push(&mut self, mut edit: MirrorEdit, ticks: &SystemChangeTick) {
+        self.redo.clear();
+        edit.tick = ticks.this_run();
+        let last_run = ticks.last_run();
+        let continued = self
+            .undo
+            .iter_mut()
+            .rev()
+            .take_while(|last| last.tick == last_run || last.tick == edit.tick)
+            .find(|last| last.entity == edit.entity && last.mirror == edit.mirror);
+        if let Some(last) = continued.filter(|last| last.tick == last_run) {
+            last.after = edit.after;
+            last.tick = edit.tick;
+            return;
+        }
+        self.push_undo(edit);
+    }
+    fn push_undo(&mut self, edit: MirrorEdit) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.undo.len() == self.capacity {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(edit);
+    }
+}
+
+/// Undo the most recent edit in [`MirrorHistory`], restoring both the mirror
+/// and the value it mirrors.
+#[derive(Event, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MirrorUndo;
+
+/// Redo the most recently undone edit in [`MirrorHistory`].
+#[derive(Event, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MirrorRedo;
+
+fn undo(world: &mut World) {
+    while let Some(edit) = world.resource_mut::<MirrorHistory>().undo.pop_back() {
+        // Edits of despawned entities can't be undone, skip them.
+        if (edit.restore)(world, edit.entity, &*edit.before) {
+            world.resource_mut::<MirrorHistory>().redo.push(edit);
+            return;
+        }
+    }
+}
+fn redo(world: &mut World) {
+    while let Some(mut edit) = world.resource_mut::<MirrorHistory>().redo.pop() {
+        if (edit.restore)(world, edit.entity, &*edit.after) {
+            // Later edits of the mirror are new edits, not a continuation of this one.
+            edit.tick = Tick::new(0);
+            world.resource_mut::<MirrorHistory>().push_undo(edit);
+            return;
+        }
+    }
+}
+fn mirror_history(
+    world: &mut World,
+    mut undos: Local<ManualEventReader<MirrorUndo>>,
+    mut redos: Local<ManualEventReader<MirrorRedo>>,
+) {
+    let undo_count = undos.read(world.resource::<Events<MirrorUndo>>()).count();
+    let redo_count = redos.read(world.resource::<Events<MirrorRedo>>()).count();
+    for _ in 0..undo_count {
+        undo(world);
+    }
+    for _ in 0..redo_count {
+        redo(world);
+    }
+}
+
+/// Record edits of mirrors in [`MirrorHistory`], and undo or redo them with
+/// the [`MirrorUndo`] and [`MirrorRedo`] events.
+///
+/// This records the edits of mirrors added by [`MirrorPlugin`](crate::MirrorPlugin)
+/// and [`MirrorResourcePlugin`](crate::MirrorResourcePlugin), each time a mirror
+/// is applied to the value it mirrors. Undoing an edit sets the mirror back
+/// to its previous value, and applies it to the mirrored value.
+///
+/// A mirror edited each frame, for example while dragging a slider, is
+/// recorded as a single edit until a frame passes without editing it.
+///
+/// Undo and redo events are handled before [`MirrorSystems::Update`], undos first.
+///
+/// ```rust
+/// use bevy_mod_component_mirror::{MirrorHistoryPlugin, MirrorRedo, MirrorUndo};
+/// use bevy::prelude::*;
+///
+/// fn undo_keys(
+///   keys: Res<Input<KeyCode>>,
+///   mut undo: EventWriter<MirrorUndo>,
+///   mut redo: EventWriter<MirrorRedo>,
+/// ) {
+///   if keys.pressed(KeyCode::ControlLeft) && keys.just_pressed(KeyCode::Z) {
+///     undo.send(MirrorUndo);
+///   }
+///   if keys.pressed(KeyCode::ControlLeft) && keys.just_pressed(KeyCode::Y) {
+///     redo.send(MirrorRedo);
+///   }
+/// }
+///
+/// fn main() {
+///   let mut app = App::new();
+///   app.add_plugins(MirrorHistoryPlugin::new().capacity(50))
+///     .add_systems(Update, undo_keys);
+/// }
+/// ```
+pub struct MirrorHistoryPlugin {
+    capacity: usize,
+    schedule: Option<InternedScheduleLabel>,
+}
+impl MirrorHistoryPlugin {
+    pub const fn new() -> Self {
+        Self {
+            capacity: 100,
+            schedule: None,
+        }
+    }
+    /// Set how many edits are kept, older edits are forgotten.
+    ///
+    /// By default, this is 100.
+    pub const fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+    /// Set the schedule in which [`MirrorUndo`] and [`MirrorRedo`] are handled.
+    ///
+    /// By default, this is [`First`].
+    pub fn schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = Some(schedule.intern());
+        self
+    }
+}
+impl Default for MirrorHistoryPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Plugin for MirrorHistoryPlugin {
+    fn build(&self, app: &mut App) {
+        let schedule = self.schedule.unwrap_or_else(|| First.intern());
+        app.add_event::<MirrorUndo>()
+            .add_event::<MirrorRedo>()
+            .add_event::<MirrorError>()
+            .insert_resource(MirrorHistory::new(self.capacity))
+            .add_systems(schedule, mirror_history.before(MirrorSystems::Update));
+    }
+}
@@ -1,13 +1,132 @@
 #![doc = include_str!("../Readme.md")]
 #![allow(clippy::wrong_self_convention)]
 
+mod history;
 mod mirror;
+mod multi_mirror;
+mod registry;
+mod resource_mirror;
+mod settings;
 
 /// Rapier mirror definitions.
 #[cfg(feature = "rapier")]
 pub mod rapier_mirrors;
 
+#[cfg(feature = "testing")]
+pub mod testing;
+
 #[cfg(feature = "rapier")]
 pub use rapier_mirrors::RapierMirrorsPlugins;
 
-pub use mirror::{Mirror, MirrorPlugin, MirrorSystems};
+pub use history::{MirrorEdit, MirrorHistory, MirrorHistoryPlugin, MirrorRedo, MirrorUndo};
+pub use mirror::{
+    ContextMirror, FromMirror, Mirror, MirrorApplied, MirrorConflict, MirrorConflictPolicy,
+    MirrorDiff, MirrorError, MirrorInsertion, MirrorMode, MirrorPaused, MirrorPlugin,
+    MirrorRefreshed, MirrorRemovalPolicy, MirrorSampling, MirrorSet, MirrorSystems, NoMirror,
+    TryMirror,
+};
+pub use multi_mirror::{MirrorSources, MultiMirror, MultiMirrorPlugin};
+pub use registry::{MirrorRegistry, ReflectMirror};
+pub use resource_mirror::MirrorResourcePlugin;
+pub use settings::MirrorSettings;
+
+/// Derive `From<&T>` and [`Mirror<T>`](trait@Mirror) for a struct mirroring `T`.
+///
+/// The mirrored type is set with `#[mirror(T)]` on the struct. By default,
+/// each field is read from and written to the field of `T` with the same name
+/// (or index for tuple structs), using [`Clone`] and [`Into`].
+/// This can be changed with the following field attributes:
+///
+/// - `#[mirror(field = "inner.radius")]`: use the `value.inner.radius` field.
+/// - `#[mirror(get = "length", set = "set_length")]`: read with `value.length()`
+///   and write with `value.set_length(mirror_field)`. Paths such as
+///   `"inner.length"` are accepted as well.
+/// - `#[mirror(skip)]`: never write this field, and use [`Default`] when
+///   creating the mirror.
+///
+/// Only the fields that changed are written, see [`MirrorDiff`].
+///
+/// ```rust
+/// use bevy_mod_component_mirror::{Mirror, MirrorPlugin};
+/// use bevy::prelude::*;
+///
+/// # mod foreign_crate {
+/// #   use bevy::prelude::*;
+/// #   #[derive(Default)] pub struct Inner { pub radius: f32 }
+/// #   #[derive(Component, Default)]
+/// #   pub struct Foreign { pub inner: Inner, length: f32 }
+/// #   impl Foreign {
+/// #     pub fn set_length(&mut self, value: f32) { self.length = value }
+/// #     pub fn length(&self) -> f32 { self.length }
+/// #   }
+/// # }
+/// use foreign_crate::Foreign;
+///
+/// #[derive(Component, Reflect, Mirror)]
+/// #[mirror(Foreign)]
+/// pub struct ForeignMirror {
+///   #[mirror(get = "length", set = "set_length")]
+///   length: f32,
+///   #[mirror(field = "inner.radius")]
+///   radius: f32,
+/// }
+///
+/// fn main() {
+///   let mut app = App::new();
+///   app.add_plugins(MirrorPlugin::<Foreign, ForeignMirror>::new());
+/// }
+/// ```
+#[cfg(feature = "derive")]
+pub use bevy_mod_component_mirror_derive::Mirror;
+
+/// Invalid uses of the [`Mirror`] derive.
+///
+/// `get` without `set`:
+///
+/// ```compile_fail
+/// # struct Foreign { length: f32 }
+/// #[derive(bevy_mod_component_mirror::Mirror)]
+/// #[mirror(Foreign)]
+/// struct ForeignMirror {
+///   #[mirror(get = "length")]
+///   length: f32,
+/// }
+/// ```
+///
+/// `field` with `get` and `set`:
+///
+/// ```compile_fail
+/// # struct Foreign { length: f32 }
+/// # impl Foreign {
+/// #   fn length(&self) -> f32 { self.length }
+/// #   fn set_length(&mut self, value: f32) { self.length = value }
+/// # }
+/// #[derive(bevy_mod_component_mirror::Mirror)]
+/// #[mirror(Foreign)]
+/// struct ForeignMirror {
+///   #[mirror(field = "length", get = "length", set = "set_length")]
+///   length: f32,
+/// }
+/// ```
+///
+/// No mirrored type:
+///
+/// ```compile_fail
+/// #[derive(bevy_mod_component_mirror::Mirror)]
+/// struct ForeignMirror {
+///   length: f32,
+/// }
+/// ```
+///
+/// On an enum:
+///
+/// ```compile_fail
+/// # enum Foreign { Empty }
+/// #[derive(bevy_mod_component_mirror::Mirror)]
+/// #[mirror(Foreign)]
+/// enum ForeignMirror {
+///   Empty,
+/// }
+/// ```
+#[cfg(all(doctest, feature = "derive"))]
+pub struct DeriveErrors;
@@ -1,6 +1,31 @@
-use std::marker::PhantomData;
+use std::{
+    any::{type_name, TypeId},
+    convert::Infallible,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem,
+    sync::Mutex,
+    time::Duration,
+};
 
-use bevy::{prelude::*, reflect::GetTypeRegistration};
+use bevy::{
+    app::{MainScheduleOrder, SpawnScene},
+    ecs::{
+        component::Tick,
+        query::ReadOnlyWorldQuery,
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+        system::{StaticSystemParam, SystemChangeTick, SystemParam, SystemParamItem},
+    },
+    prelude::*,
+    reflect::{GetTypeRegistration, ReflectRef},
+    time::TimeSystem,
+    utils::{HashMap, HashSet},
+};
+
+use crate::history::{MirrorEdit, MirrorHistory};
+use crate::registry::register_mirror;
+use crate::settings::{mirror_enabled, toggled, MirrorSettings};
 
 /// Mirror `T`
 /// If you wish to mirror other components, you need to do the following:
@@ -47,80 +72,1337 @@ use bevy::{prelude::*, reflect::GetTypeRegistration};
 /// }
 ///
 /// ```
+///
+/// If the mirror stores [`Entity`]s, implement [`MapEntities`] for it
+/// and add `#[reflect(MapEntities)]`, so that the entities are remapped
+/// when the mirror is loaded from a scene.
+///
+/// [`MapEntities`]: bevy::ecs::entity::MapEntities
 pub trait Mirror<T>: for<'a> From<&'a T> {
     fn apply(&self, val: &mut T);
+
+    /// Apply to `val` only the fields of `self` listed in `diff`.
+    ///
+    /// Override this to avoid overwriting state of `T` that is not affected
+    /// by the edit. By default, this calls [`Mirror::apply`].
+    fn apply_diff(&self, val: &mut T, diff: &MirrorDiff) {
+        let _ = diff;
+        self.apply(val);
+    }
+}
+
+/// Mirror `T`, rejecting mirror values that can't be applied to `T`.
+///
+/// This is a fallible version of [`Mirror`], all [`Mirror`]s implement
+/// `TryMirror` with [`Infallible`] as error.
+///
+/// When [`TryMirror::try_apply`] fails, [`MirrorPlugin`] leaves `T` untouched
+/// and sends a [`MirrorError`] event.
+///
+/// ```rust
+/// use bevy_mod_component_mirror::{TryMirror, MirrorPlugin};
+/// use bevy::prelude::*;
+///
+/// # #[derive(Component)] pub struct Foreign { length: f32 }
+/// #[derive(Component, Reflect)]
+/// pub struct ForeignMirror {
+///   inner: f32,
+/// }
+/// # impl<'a> From<&'a Foreign> for ForeignMirror {
+/// #   fn from(value: &'a Foreign) -> Self { ForeignMirror { inner: value.length } }
+/// # }
+/// // ForeignMirror → Foreign, only if inner is positive
+/// impl TryMirror<Foreign> for ForeignMirror {
+///   type Error = &'static str;
+///
+///   fn try_apply(&self, value: &mut Foreign) -> Result<(), Self::Error> {
+///     if self.inner < 0.0 {
+///       return Err("length must be positive");
+///     }
+///     value.length = self.inner;
+///     Ok(())
+///   }
+/// }
+///
+/// fn main() {
+///   let mut app = App::new();
+///   app.add_plugins(MirrorPlugin::<Foreign, ForeignMirror>::new());
+/// }
+/// ```
+pub trait TryMirror<T>: for<'a> From<&'a T> {
+    /// Why `self` can't be applied to `T`.
+    type Error: fmt::Display;
+
+    /// Apply `self` to `val`.
+    ///
+    /// When returning an error, `val` must be left unchanged.
+    fn try_apply(&self, val: &mut T) -> Result<(), Self::Error>;
+
+    /// Apply to `val` only the fields of `self` listed in `diff`.
+    ///
+    /// This is what [`MirrorPlugin`] calls when `self` is edited.
+    /// Override this to avoid overwriting state of `T` that is not affected
+    /// by the edit. By default, this calls [`TryMirror::try_apply`].
+    fn try_apply_diff(&self, val: &mut T, diff: &MirrorDiff) -> Result<(), Self::Error> {
+        let _ = diff;
+        self.try_apply(val)
+    }
+}
+impl<T, U: Mirror<T>> TryMirror<T> for U {
+    type Error = Infallible;
+
+    fn try_apply(&self, val: &mut T) -> Result<(), Self::Error> {
+        self.apply(val);
+        Ok(())
+    }
+    fn try_apply_diff(&self, val: &mut T, diff: &MirrorDiff) -> Result<(), Self::Error> {
+        self.apply_diff(val, diff);
+        Ok(())
+    }
+}
+
+/// Mirror `T`, with access to the world through the system parameter `Param`.
+///
+/// [`From<&T>`] and [`TryMirror`] only see `T`. Implement this instead when the
+/// conversions need something else, such as an asset, the [`Name`] of an entity
+/// or a resource. All [`TryMirror`]s implement `ContextMirror` with `()` as `Param`.
+///
+/// `Param` is shared between threads, so it is passed by reference.
+/// Use [`ParallelCommands`](bevy::ecs::system::ParallelCommands) rather than [`Commands`]. `Param` must not
+/// access `T` or the mirror mutably.
+///
+/// ```rust
+/// use bevy_mod_component_mirror::{ContextMirror, MirrorDiff, MirrorPlugin};
+/// use bevy::{ecs::system::SystemParamItem, prelude::*};
+/// use std::convert::Infallible;
+///
+/// # #[derive(Component)] pub struct Foreign { length: f32 }
+/// #[derive(Resource)]
+/// pub struct PhysicsScale(f32);
+///
+/// /// `Foreign` length, in meters rather than physics units.
+/// #[derive(Component, Reflect)]
+/// pub struct ForeignMirror {
+///   meters: f32,
+/// }
+/// impl ContextMirror<Foreign> for ForeignMirror {
+///   type Param = Res<'static, PhysicsScale>;
+///   type Error = Infallible;
+///
+///   fn from_source(value: &Foreign, scale: &SystemParamItem<Self::Param>) -> Self {
+///     ForeignMirror { meters: value.length * scale.0 }
+///   }
+///   fn apply_to(
+///     &self,
+///     value: &mut Foreign,
+///     _: &MirrorDiff,
+///     scale: &SystemParamItem<Self::Param>,
+///   ) -> Result<(), Infallible> {
+///     value.length = self.meters / scale.0;
+///     Ok(())
+///   }
+/// }
+///
+/// fn main() {
+///   let mut app = App::new();
+///   app.insert_resource(PhysicsScale(0.1))
+///     .add_plugins(MirrorPlugin::<Foreign, ForeignMirror>::new());
+/// }
+/// ```
+pub trait ContextMirror<T>: Sized {
+    /// What the conversions can access, such as `Res<'static, Assets<Mesh>>`.
+    type Param: for<'w, 's> SystemParam<Item<'w, 's>: Sync>;
+    /// Why `self` can't be applied to `T`.
+    type Error: fmt::Display;
+
+    /// Create the mirror of `val`.
+    fn from_source(val: &T, param: &SystemParamItem<Self::Param>) -> Self;
+
+    /// Apply to `val` the fields of `self` listed in `diff`.
+    ///
+    /// When returning an error, `val` must be left unchanged.
+    fn apply_to(
+        &self,
+        val: &mut T,
+        diff: &MirrorDiff,
+        param: &SystemParamItem<Self::Param>,
+    ) -> Result<(), Self::Error>;
+}
+impl<T, U: TryMirror<T>> ContextMirror<T> for U {
+    type Param = ();
+    type Error = U::Error;
+
+    fn from_source(val: &T, (): &()) -> Self {
+        U::from(val)
+    }
+    fn apply_to(&self, val: &mut T, diff: &MirrorDiff, (): &()) -> Result<(), Self::Error> {
+        self.try_apply_diff(val, diff)
+    }
+}
+
+/// The fields of a mirror that differ from the current value of the
+/// component it mirrors.
+///
+/// [`MirrorPlugin`] compares the fields of `U` with `U::from(&T)` using
+/// [`Reflect::reflect_partial_eq`], and passes the result to
+/// [`TryMirror::try_apply_diff`].
+///
+/// Only the top-level fields are compared. Enum variants are compared
+/// field by field only when the variant didn't change. Other kind of
+/// values are always entirely changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MirrorDiff {
+    /// `None` when everything changed.
+    fields: Option<Vec<String>>,
+}
+impl MirrorDiff {
+    /// A diff where every field changed.
+    pub const fn all() -> Self {
+        Self { fields: None }
+    }
+    /// Compare `old` and `new`.
+    pub fn new(old: &dyn Reflect, new: &dyn Reflect) -> Self {
+        if old.reflect_partial_eq(new) == Some(true) {
+            return Self {
+                fields: Some(Vec::new()),
+            };
+        }
+        let changed = |old: Option<&dyn Reflect>, new: Option<&dyn Reflect>| match (old, new) {
+            (Some(old), Some(new)) => old.reflect_partial_eq(new) != Some(true),
+            _ => true,
+        };
+        let fields = match (old.reflect_ref(), new.reflect_ref()) {
+            (ReflectRef::Struct(old), ReflectRef::Struct(new)) => (0..old.field_len())
+                .filter_map(|i| old.name_at(i).zip(old.field_at(i)))
+                .filter(|(name, field)| changed(Some(*field), new.field(name)))
+                .map(|(name, _)| name.to_owned())
+                .collect(),
+            (ReflectRef::TupleStruct(old), ReflectRef::TupleStruct(new)) => (0..old.field_len())
+                .filter(|i| changed(old.field(*i), new.field(*i)))
+                .map(|i| i.to_string())
+                .collect(),
+            (ReflectRef::Enum(old), ReflectRef::Enum(new))
+                if old.variant_name() == new.variant_name() =>
+            {
+                old.iter_fields()
+                    .enumerate()
+                    .filter(|(i, field)| match field.name() {
+                        Some(name) => changed(Some(field.value()), new.field(name)),
+                        None => changed(Some(field.value()), new.field_at(*i)),
+                    })
+                    .map(|(i, field)| field.name().map_or_else(|| i.to_string(), str::to_owned))
+                    .collect()
+            }
+            _ => return Self::all(),
+        };
+        Self {
+            fields: Some(fields),
+        }
+    }
+    /// Whether nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self.fields.as_ref().is_some_and(Vec::is_empty)
+    }
+    /// Whether `field` changed. Tuple fields are named by their index.
+    pub fn is_changed(&self, field: &str) -> bool {
+        self.fields
+            .as_ref()
+            .is_none_or(|fields| fields.iter().any(|f| f == field))
+    }
+    /// The name of changed fields, `None` if everything changed.
+    pub fn fields(&self) -> Option<impl Iterator<Item = &str>> {
+        let fields = self.fields.as_ref()?;
+        Some(fields.iter().map(String::as_str))
+    }
+}
+
+/// Sent by [`MirrorPlugin`], [`MirrorResourcePlugin`](crate::MirrorResourcePlugin)
+/// and [`MultiMirrorPlugin`](crate::MultiMirrorPlugin) when a mirror couldn't be
+/// applied to the value it mirrors, see [`TryMirror`].
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct MirrorError {
+    /// The entity with the mirror, `None` for resources.
+    pub entity: Option<Entity>,
+    /// The type name of the mirrored component.
+    pub source: &'static str,
+    /// The type name of the mirror component.
+    pub mirror: &'static str,
+    /// Why the mirror couldn't be applied.
+    pub message: String,
+}
+impl MirrorError {
+    pub(crate) fn new<T, U>(entity: Option<Entity>, error: &dyn fmt::Display) -> Self {
+        Self {
+            entity,
+            source: type_name::<T>(),
+            mirror: type_name::<U>(),
+            message: error.to_string(),
+        }
+    }
+}
+impl fmt::Display for MirrorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            entity,
+            source,
+            mirror,
+            message,
+        } = self;
+        match entity {
+            Some(entity) => write!(
+                f,
+                "Couldn't apply {mirror} to {source} of {entity:?}: {message}"
+            ),
+            None => write!(f, "Couldn't apply {mirror} to {source}: {message}"),
+        }
+    }
+}
+
+/// Sent by [`MirrorPlugin`] and [`MirrorResourcePlugin`](crate::MirrorResourcePlugin)
+/// when both the mirrored value and its mirror were edited since the last
+/// update, with [`MirrorConflictPolicy::Report`].
+#[derive(Event, Debug)]
+pub struct MirrorConflict {
+    /// The entity with the mirror, `None` for resources.
+    pub entity: Option<Entity>,
+    /// The type name of the mirrored component.
+    pub source: &'static str,
+    /// The type name of the mirror component.
+    pub mirror: &'static str,
+    /// The edited mirror value, overwritten by the value of the mirrored component.
+    pub lost: Box<dyn Reflect>,
+}
+impl MirrorConflict {
+    pub(crate) fn new<T, U: Reflect>(entity: Option<Entity>, lost: U) -> Self {
+        Self {
+            entity,
+            source: type_name::<T>(),
+            mirror: type_name::<U>(),
+            lost: Box::new(lost),
+        }
+    }
 }
-fn reflect_mirror_add<T: Component, U: Mirror<T> + Component>(
-    query: Query<(Entity, &T), Added<T>>,
+
+/// Sent by [`MirrorPlugin`] when the mirror of `T` on `entity` was applied
+/// to `T`, for example after an edit in an inspector.
+///
+/// Only sent with [`MirrorPlugin::send_events`].
+///
+/// ```rust
+/// use bevy_mod_component_mirror::MirrorApplied;
+/// use bevy::prelude::*;
+/// # #[derive(Component)] pub struct Collider;
+///
+/// fn wake_edited(mut applied: EventReader<MirrorApplied<Collider>>) {
+///   for applied in applied.read() {
+///     info!("collider of {:?} edited: {:?}", applied.entity, applied.after);
+///   }
+/// }
+/// ```
+#[derive(Event)]
+pub struct MirrorApplied<T: Component> {
+    /// The entity with `T` and its mirror.
+    pub entity: Entity,
+    /// The mirror of `T` before it was edited.
+    pub before: Box<dyn Reflect>,
+    /// The mirror applied to `T`.
+    pub after: Box<dyn Reflect>,
+    _source: PhantomData<fn() -> T>,
+}
+
+/// Sent by [`MirrorPlugin`] when the mirror of `T` on `entity` was updated
+/// with the value of `T`, after `T` changed.
+///
+/// Only sent with [`MirrorPlugin::send_events`].
+#[derive(Event)]
+pub struct MirrorRefreshed<T: Component> {
+    /// The entity with `T` and its mirror.
+    pub entity: Entity,
+    /// The previous value of the mirror.
+    pub before: Box<dyn Reflect>,
+    /// The new value of the mirror, set from `T`.
+    pub after: Box<dyn Reflect>,
+    _source: PhantomData<fn() -> T>,
+}
+
+macro_rules! impl_mirror_event {
+    ($event:ident) => {
+        impl<T: Component> $event<T> {
+            fn new(entity: Entity, before: Box<dyn Reflect>, after: &dyn Reflect) -> Self {
+                Self {
+                    entity,
+                    before,
+                    after: after.clone_value(),
+                    _source: PhantomData,
+                }
+            }
+        }
+        impl<T: Component> fmt::Debug for $event<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(&format!("{}<{}>", stringify!($event), type_name::<T>()))
+                    .field("entity", &self.entity)
+                    .field("before", &self.before)
+                    .field("after", &self.after)
+                    .finish()
+            }
+        }
+    };
+}
+impl_mirror_event!(MirrorApplied);
+impl_mirror_event!(MirrorRefreshed);
+
+/// Create `T` from its mirror `U`.
+///
+/// This lets you author `T` using only `U`, for example by spawning entities
+/// with `U` or loading a scene containing `U`.
+/// Enable it with [`MirrorPlugin::create_source`].
+///
+/// When this returns an error, `T` isn't added and a [`MirrorError`] is sent.
+///
+/// `param` is the [`ContextMirror::Param`] of `U`, it is `&()` for
+/// [`Mirror`] and [`TryMirror`] implementations.
+///
+/// ```rust
+/// use bevy_mod_component_mirror::{FromMirror, Mirror, MirrorPlugin};
+/// use bevy::prelude::*;
+/// use std::convert::Infallible;
+///
+/// # #[derive(Component, Default)] pub struct Foreign { length: f32 }
+/// #[derive(Component, Reflect)]
+/// pub struct ForeignMirror {
+///   inner: f32,
+/// }
+/// # impl<'a> From<&'a Foreign> for ForeignMirror {
+/// #   fn from(value: &'a Foreign) -> Self { ForeignMirror { inner: value.length } }
+/// # }
+/// # impl Mirror<Foreign> for ForeignMirror {
+/// #   fn apply(&self, value: &mut Foreign) { value.length = self.inner; }
+/// # }
+/// // ForeignMirror → new Foreign
+/// impl FromMirror<ForeignMirror> for Foreign {
+///   fn from_mirror(mirror: &ForeignMirror, (): &()) -> Result<Self, Infallible> {
+///     let mut value = Foreign::default();
+///     mirror.apply(&mut value);
+///     Ok(value)
+///   }
+/// }
+///
+/// fn main() {
+///   let mut app = App::new();
+///   app.add_plugins(MirrorPlugin::<Foreign, ForeignMirror>::new().create_source());
+/// }
+/// ```
+pub trait FromMirror<U: ContextMirror<Self>>: Sized {
+    fn from_mirror(mirror: &U, param: &SystemParamItem<U::Param>) -> Result<Self, U::Error>;
+}
+#[allow(clippy::type_complexity)]
+fn reflect_mirror_add<T: Component, U: ContextMirror<T> + Component, F: ReadOnlyWorldQuery>(
+    query: Query<(Entity, &T), (Added<T>, Without<U>, Without<NoMirror<T>>, F)>,
+    mut allowed: RemovedComponents<NoMirror<T>>,
+    sources: Query<&T, (Without<U>, Without<NoMirror<T>>, F)>,
+    param: StaticSystemParam<U::Param>,
     mut cmds: Commands,
 ) {
     for (entity, added) in &query {
-        cmds.entity(entity).insert(U::from(added));
+        cmds.entity(entity).insert(U::from_source(added, &param));
+    }
+    for entity in allowed.read() {
+        if let Ok(source) = sources.get(entity) {
+            cmds.entity(entity).insert(U::from_source(source, &param));
+        }
+    }
+}
+/// Add `U` to entities once they match `F`, and remove it from entities
+/// that stop matching `F`.
+///
+/// Only added when `F` isn't `()`, [`reflect_mirror_add`] only checks `F`
+/// when `T` is added.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn reflect_mirror_filter<T: Component, U: ContextMirror<T> + Component, F: ReadOnlyWorldQuery>(
+    added: Query<Entity, (Added<T>, Without<U>, Without<NoMirror<T>>)>,
+    mut allowed: RemovedComponents<NoMirror<T>>,
+    mirrored: Query<Entity, (With<T>, With<U>, Without<NoMirror<T>>)>,
+    sources: Query<&T, (Without<U>, Without<NoMirror<T>>)>,
+    matching: Query<(), F>,
+    param: StaticSystemParam<U::Param>,
+    mut filtered_out: Local<HashSet<Entity>>,
+    mut cmds: Commands,
+) {
+    let unmatched = |entity: &Entity| !matching.contains(*entity);
+    filtered_out.retain(|&entity| {
+        // Forget entities that lost `T` or got `NoMirror<T>`.
+        let Ok(source) = sources.get(entity) else {
+            return false;
+        };
+        if unmatched(&entity) {
+            return true;
+        }
+        cmds.entity(entity).insert(U::from_source(source, &param));
+        false
+    });
+    filtered_out.extend(added.iter().filter(unmatched));
+    filtered_out.extend(allowed.read().filter(unmatched));
+    for entity in mirrored.iter().filter(unmatched) {
+        cmds.entity(entity).remove::<U>();
+        filtered_out.insert(entity);
     }
 }
 #[allow(clippy::type_complexity)]
-fn reflect_mirror_component<T: Component, U: Mirror<T> + Component>(
-    mut query: ParamSet<(
-        (Query<(Entity, &T), Changed<T>>, Query<&mut U>),
-        (Query<&mut T>, Query<(Entity, &U), Changed<U>>),
-    )>,
+fn reflect_source_add<T: Component, U: ContextMirror<T> + Component>(
+    query: Query<(Entity, &U), (Added<U>, Without<T>)>,
+    options: Res<MirrorOptions<T, U>>,
+    param: StaticSystemParam<U::Param>,
+    mut errors: EventWriter<MirrorError>,
+    mut cmds: Commands,
+) {
+    let Some(create_source) = options.create_source else {
+        return;
+    };
+    for (entity, added) in &query {
+        match create_source(added, &param) {
+            Ok(source) => {
+                cmds.entity(entity).insert(source);
+            }
+            Err(error) => errors.send(MirrorError::new::<T, U>(Some(entity), &error)),
+        }
+    }
+}
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn reflect_mirror_remove<T: Component, U: ContextMirror<T> + Component, F: ReadOnlyWorldQuery>(
+    mut removed_sources: RemovedComponents<T>,
+    mut removed_mirrors: RemovedComponents<U>,
+    forbidden: Query<Entity, (Added<NoMirror<T>>, With<U>)>,
+    mirrors: Query<Entity, (With<T>, With<U>)>,
+    sources: Query<(Entity, &T), (Without<U>, Without<NoMirror<T>>, F)>,
+    options: Res<MirrorOptions<T, U>>,
+    settings: Res<MirrorSettings>,
+    param: StaticSystemParam<U::Param>,
+    mut was_disabled: Local<bool>,
+    mut cmds: Commands,
 ) {
-    let (changed, mut to_update) = query.p0();
-    for (entity, changed) in &changed {
-        if let Ok(mut to_update) = to_update.get_mut(entity) {
-            *to_update = changed.into()
+    match toggled::<T>(&settings, &mut was_disabled) {
+        Some(true) => {
+            // Mirrors removed when disabling were not removed by the user.
+            removed_mirrors.clear();
+            for (entity, source) in &sources {
+                cmds.entity(entity).insert(U::from_source(source, &param));
+            }
+        }
+        Some(false) => {
+            for entity in &mirrors {
+                cmds.entity(entity).remove::<U>();
+            }
+        }
+        None => {}
+    }
+    if *was_disabled {
+        removed_mirrors.clear();
+    }
+    for entity in removed_sources.read() {
+        if let Some(mut entity) = cmds.get_entity(entity) {
+            entity.remove::<U>();
+        }
+    }
+    for entity in &forbidden {
+        cmds.entity(entity).remove::<U>();
+    }
+    for entity in removed_mirrors.read() {
+        let Ok((_, source)) = sources.get(entity) else {
+            continue;
+        };
+        match options.removal {
+            MirrorRemovalPolicy::Readd => {
+                cmds.entity(entity).insert(U::from_source(source, &param));
+            }
+            MirrorRemovalPolicy::RemoveSource if options.mode.writes_source() => {
+                cmds.entity(entity).remove::<T>();
+            }
+            MirrorRemovalPolicy::Ignore | MirrorRemovalPolicy::RemoveSource => {}
+        }
+    }
+}
+/// Whether `left` and `right` are known to be equal, using [`Reflect::reflect_partial_eq`].
+pub(crate) fn mirror_eq<U: Reflect>(left: &U, right: &U) -> bool {
+    left.reflect_partial_eq(right).unwrap_or(false)
+}
+/// What [`refresh_mirror`] did.
+pub(crate) enum Refresh<U> {
+    /// `mirror` already had the value of `source`, or won a conflict.
+    Unchanged,
+    /// `mirror` was set to the value of `source`, this is its previous value.
+    Updated(U),
+    /// `mirror` was set to the value of `source`, this is the conflicting
+    /// edit it overwrote.
+    Conflict(U),
+}
+/// Set `mirror` to the value of `source`, unless it already has this value.
+///
+/// If `mirror` was edited as well, this is a conflict resolved with `policy`.
+pub(crate) fn refresh_mirror<T, U: ContextMirror<T> + Reflect>(
+    mode: MirrorMode,
+    policy: MirrorConflictPolicy,
+    source: &T,
+    mut mirror: Mut<U>,
+    param: &SystemParamItem<U::Param>,
+) -> Refresh<U> {
+    let new = U::from_source(source, param);
+    if mirror_eq(&*mirror, &new) {
+        return Refresh::Unchanged;
+    }
+    // A newly added `mirror` was set from `source`, it can't conflict with it.
+    let conflict = mode.writes_source() && mirror.is_changed() && !mirror.is_added();
+    match policy {
+        MirrorConflictPolicy::MirrorWins if conflict => Refresh::Unchanged,
+        MirrorConflictPolicy::Report if conflict => {
+            Refresh::Conflict(mem::replace(&mut *mirror, new))
         }
+        _ => Refresh::Updated(mem::replace(&mut *mirror, new)),
     }
-    let (mut to_update, changed) = query.p1();
-    for (entity, changed) in &changed {
-        if let Ok(mut to_update) = to_update.get_mut(entity) {
-            changed.apply(&mut to_update)
+}
+/// Apply to `source` the fields of `mirror` that differ from `last`, the value
+/// of `mirror` when it was last synced with `source`. Without `last`, apply
+/// the fields that differ from `source`.
+///
+/// Returns the mirror of `source` before it was edited, `None` if nothing changed.
+pub(crate) fn apply_mirror<T, U: ContextMirror<T> + Reflect>(
+    mirror: &U,
+    last: Option<&dyn Reflect>,
+    mut source: Mut<T>,
+    param: &SystemParamItem<U::Param>,
+) -> Result<Option<U>, U::Error> {
+    // Inspectors may mutably access `mirror` without editing it.
+    let before = U::from_source(&*source, param);
+    let diff = MirrorDiff::new(last.unwrap_or(&before), mirror);
+    if diff.is_empty() {
+        return Ok(None);
+    }
+    mirror.apply_to(source.bypass_change_detection(), &diff, param)?;
+    source.set_changed();
+    Ok(Some(before))
+}
+/// When [`reflect_mirror_component`] last updated `U` with changes to `T`.
+#[derive(Default)]
+struct LastSample {
+    frames: u32,
+    time: Option<Duration>,
+    tick: Option<Tick>,
+}
+/// The value of each mirror `U` when it was last synced with `T`, only kept
+/// when not sampling every frame.
+///
+/// `U` may then miss changes to `T`, so edits to `U` are found by comparing
+/// it with this value rather than with the current value of `T`.
+#[derive(Resource)]
+pub(crate) struct MirrorSnapshots<T, U> {
+    mirrors: HashMap<Entity, Box<dyn Reflect>>,
+    _types: PhantomData<fn() -> (T, U)>,
+}
+impl<T, U> Default for MirrorSnapshots<T, U> {
+    fn default() -> Self {
+        Self {
+            mirrors: HashMap::new(),
+            _types: PhantomData,
         }
     }
 }
+impl<T, U> MirrorSnapshots<T, U> {
+    /// Set the snapshot of the mirror on `entity` to `value`, if it has one.
+    ///
+    /// Used when the mirror and `T` were synced outside of [`MirrorPlugin`] systems.
+    pub(crate) fn refresh(&mut self, entity: Entity, value: &dyn Reflect) {
+        if let Some(snapshot) = self.mirrors.get_mut(&entity) {
+            *snapshot = value.clone_value();
+        }
+    }
+}
+impl LastSample {
+    /// Whether changes to `T` should be sampled this frame, following `sampling`.
+    fn is_due(&mut self, sampling: MirrorSampling, now: Option<Duration>) -> bool {
+        self.frames += 1;
+        let due = match (sampling, self.time, now) {
+            (MirrorSampling::EveryFrame, ..) => true,
+            (MirrorSampling::Frames(frames), ..) => self.frames >= frames,
+            (MirrorSampling::Interval(interval), Some(last), Some(now)) => now - last >= interval,
+            (MirrorSampling::Interval(_), ..) => true,
+        };
+        if due {
+            self.frames = 0;
+            self.time = now;
+        }
+        due
+    }
+}
+/// The values collected by a parallel query, sorted by entity so that their
+/// order doesn't depend on how the query was split between threads.
+fn sorted<V>(values: Mutex<Vec<V>>, entity: fn(&V) -> Option<Entity>) -> Vec<V> {
+    let mut values = values.into_inner().unwrap();
+    values.sort_unstable_by_key(entity);
+    values
+}
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn reflect_mirror_component<
+    T: Component,
+    U: ContextMirror<T> + Component + Reflect,
+    F: ReadOnlyWorldQuery,
+>(
+    mut query: Query<(Entity, &mut T, &mut U), (F, Synced<T>)>,
+    param: StaticSystemParam<U::Param>,
+    mut resumed: RemovedComponents<MirrorPaused<T>>,
+    mut removed: RemovedComponents<U>,
+    options: Res<MirrorOptions<T, U>>,
+    time: Option<Res<Time<Real>>>,
+    ticks: SystemChangeTick,
+    mut last_sample: Local<LastSample>,
+    mut snapshots: ResMut<MirrorSnapshots<T, U>>,
+    history: Option<ResMut<MirrorHistory>>,
+    applied: Option<ResMut<Events<MirrorApplied<T>>>>,
+    refreshed: Option<ResMut<Events<MirrorRefreshed<T>>>>,
+    mut errors: EventWriter<MirrorError>,
+    mut conflicts: EventWriter<MirrorConflict>,
+) {
+    let mode = options.mode;
+    let resumed: HashSet<Entity> = resumed.read().collect();
+    let now = time.map(|time| time.elapsed());
+    let sample = mode.writes_mirror() && last_sample.is_due(options.sampling, now);
+    // Changes to `T` since the last sample, not only since the last frame.
+    let since = *last_sample.tick.get_or_insert(ticks.last_run());
+    if sample {
+        last_sample.tick = Some(ticks.this_run());
+    }
+    let sampled = options.sampling != MirrorSampling::EveryFrame;
+    let track = mode.writes_source() && mode.writes_mirror() && sampled;
+    if track {
+        for entity in removed.read() {
+            snapshots.mirrors.remove(&entity);
+        }
+    } else {
+        removed.clear();
+        snapshots.mirrors.clear();
+    }
+    let last_synced = track.then_some(&snapshots.mirrors);
+    let new_snapshots = Mutex::new(Vec::new());
+    let new_errors = Mutex::new(Vec::new());
+    let new_conflicts = Mutex::new(Vec::new());
+    let new_edits = Mutex::new(Vec::new());
+    let new_applied = Mutex::new(Vec::new());
+    let new_refreshed = Mutex::new(Vec::new());
+    let record = history.is_some();
+    let send_events = options.send_events;
+    let param = &*param;
+
+    query
+        .par_iter_mut()
+        .for_each(|(entity, source, mut mirror)| {
+            // A newly added mirror was set from `T`, older values are irrelevant.
+            let last = last_synced
+                .and_then(|snapshots| snapshots.get(&entity))
+                .filter(|_| !mirror.is_added());
+            let mut failed = false;
+            let synced = 'sync: {
+                let was_resumed = mode.writes_mirror() && resumed.contains(&entity);
+                let source_changed =
+                    sample && source.last_changed().is_newer_than(since, ticks.this_run());
+                let mirror_changed = mode.writes_source() && mirror.is_changed();
+                if !was_resumed && !source_changed && !mirror_changed {
+                    break 'sync false;
+                }
+                // `U` missed changes to `T` while paused, `T` is always right.
+                let policy = if was_resumed {
+                    MirrorConflictPolicy::SourceWins
+                } else {
+                    options.conflict
+                };
+                let refresh = if was_resumed || source_changed {
+                    refresh_mirror(mode, policy, &*source, mirror.reborrow(), param)
+                } else {
+                    Refresh::Unchanged
+                };
+                match refresh {
+                    Refresh::Unchanged => {}
+                    // `mirror` was just set from `T`: avoid echoing it back.
+                    Refresh::Updated(before) => {
+                        if send_events {
+                            let event = MirrorRefreshed::new(entity, Box::new(before), &*mirror);
+                            new_refreshed.lock().unwrap().push(event);
+                        }
+                        break 'sync true;
+                    }
+                    Refresh::Conflict(lost) => {
+                        if send_events {
+                            let event = MirrorRefreshed::new(entity, lost.clone_value(), &*mirror);
+                            new_refreshed.lock().unwrap().push(event);
+                        }
+                        let conflict = MirrorConflict::new::<T, U>(Some(entity), lost);
+                        new_conflicts.lock().unwrap().push(conflict);
+                        break 'sync true;
+                    }
+                }
+                if !mirror_changed {
+                    break 'sync false;
+                }
+                match apply_mirror(&*mirror, last.map(|last| &**last), source, param) {
+                    Ok(Some(before)) => {
+                        if send_events {
+                            let event = MirrorApplied::new(entity, before.clone_value(), &*mirror);
+                            new_applied.lock().unwrap().push(event);
+                        }
+                        if record {
+                            let edit = MirrorEdit::component::<T, U>(entity, before, &mirror);
+                            new_edits.lock().unwrap().push(edit);
+                        }
+                        true
+                    }
+                    Ok(None) => false,
+                    Err(error) => {
+                        failed = true;
+                        let error = MirrorError::new::<T, U>(Some(entity), &error);
+                        new_errors.lock().unwrap().push(error);
+                        false
+                    }
+                }
+            };
+            // Rejected edits are not recorded, so that they are applied
+            // again along with the edits fixing them.
+            if last_synced.is_some() && (synced || (last.is_none() && !failed)) {
+                let snapshot = (entity, mirror.clone_value());
+                new_snapshots.lock().unwrap().push(snapshot);
+            }
+        });
+    snapshots
+        .mirrors
+        .extend(new_snapshots.into_inner().unwrap());
+    errors.send_batch(sorted(new_errors, |e: &MirrorError| e.entity));
+    conflicts.send_batch(sorted(new_conflicts, |e: &MirrorConflict| e.entity));
+    if let Some(mut applied) = applied {
+        applied.extend(sorted(new_applied, |e: &MirrorApplied<T>| Some(e.entity)));
+    }
+    if let Some(mut refreshed) = refreshed {
+        refreshed.extend(sorted(new_refreshed, |e: &MirrorRefreshed<T>| {
+            Some(e.entity)
+        }));
+    }
+    if let Some(mut history) = history {
+        sorted(new_edits, |e: &MirrorEdit| e.entity)
+            .into_iter()
+            .for_each(|edit| history.push(edit, &ticks));
+    }
+}
 
 /// Systems added by the [`MirrorPlugin`].
+///
+/// To only order the systems of a specific mirror, use [`MirrorSet`].
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
 pub enum MirrorSystems {
-    /// When the mirror component is updated, during [`First`].
+    /// When the mirror component is updated, by default during [`First`].
+    ///
+    /// See [`MirrorPlugin::update_schedule`].
     Update,
     /// When mirror components get added to entites with the component they
-    /// mirror (if not already present), in [`Last`].
+    /// mirror (if not already present), by default in [`Last`].
+    ///
+    /// See [`MirrorPlugin::add_schedule`]. With [`MirrorPlugin::insert_same_frame`],
+    /// this also runs in [`MirrorInsertion`].
+    ///
+    /// This is also when the mirrored component is added to entities with
+    /// only the mirror, if [`MirrorPlugin::create_source`] is enabled.
     Add,
+    /// When mirror components get removed from entities that lost the component
+    /// they mirror, and when [`MirrorRemovalPolicy`] is enforced.
+    ///
+    /// This runs in the same schedule as [`MirrorSystems::Add`].
+    Remove,
+}
+
+/// Schedule running right before [`PostUpdate`], to add mirrors to entities
+/// spawned in the same frame.
+///
+/// Only used with [`MirrorPlugin::insert_same_frame`].
+#[derive(ScheduleLabel, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MirrorInsertion;
+
+/// All systems added by the [`MirrorPlugin`] or
+/// [`MirrorResourcePlugin`](crate::MirrorResourcePlugin) mirroring `T`.
+///
+/// Unlike [`MirrorSystems`], this only contains the systems for `T`,
+/// so you can order your systems relative to a single mirror.
+///
+/// ```rust
+/// use bevy_mod_component_mirror::MirrorSet;
+/// use bevy::prelude::*;
+/// # #[derive(Component)] pub struct Foreign;
+///
+/// fn after_mirror() {}
+///
+/// # fn main() {
+/// # let mut app = App::new();
+/// app.add_systems(First, after_mirror.after(MirrorSet::<Foreign>::new()));
+/// # }
+/// ```
+#[derive(SystemSet)]
+pub struct MirrorSet<T: 'static>(PhantomData<fn() -> T>);
+impl<T: 'static> MirrorSet<T> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+impl<T: 'static> Default for MirrorSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T: 'static> Clone for MirrorSet<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T: 'static> Copy for MirrorSet<T> {}
+impl<T: 'static> PartialEq for MirrorSet<T> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+impl<T: 'static> Eq for MirrorSet<T> {}
+impl<T: 'static> Hash for MirrorSet<T> {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+impl<T: 'static> fmt::Debug for MirrorSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MirrorSet<{}>", type_name::<T>())
+    }
+}
+
+/// Entities with `T` that are still mirrored, see [`NoMirror`] and [`MirrorPaused`].
+type Synced<T> = (Without<NoMirror<T>>, Without<MirrorPaused<T>>);
+
+/// Never mirror `T` on this entity.
+///
+/// [`MirrorPlugin`] doesn't add the mirror of `T` to entities with `NoMirror<T>`,
+/// and removes it if already present, regardless of [`MirrorRemovalPolicy`].
+/// The mirror is added back when `NoMirror<T>` is removed.
+///
+/// ```rust
+/// use bevy_mod_component_mirror::NoMirror;
+/// use bevy::prelude::*;
+/// # #[derive(Component)] pub struct Foreign;
+///
+/// fn spawn_debris(mut cmds: Commands) {
+///   cmds.spawn((Foreign, NoMirror::<Foreign>::new()));
+/// }
+/// ```
+#[derive(Component)]
+pub struct NoMirror<T: Component>(PhantomData<fn() -> T>);
+
+/// Stop syncing `T` and its mirror on this entity, in both directions.
+///
+/// Unlike [`NoMirror`], the mirror is kept. When `MirrorPaused<T>` is removed,
+/// the mirror is updated with the current value of `T`, and edits made to
+/// the mirror while paused are lost.
+#[derive(Component)]
+pub struct MirrorPaused<T: Component>(PhantomData<fn() -> T>);
+
+macro_rules! impl_marker {
+    ($marker:ident) => {
+        impl<T: Component> $marker<T> {
+            pub const fn new() -> Self {
+                Self(PhantomData)
+            }
+        }
+        impl<T: Component> Default for $marker<T> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+        impl<T: Component> Clone for $marker<T> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+        impl<T: Component> Copy for $marker<T> {}
+        impl<T: Component> fmt::Debug for $marker<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}<{}>", stringify!($marker), type_name::<T>())
+            }
+        }
+    };
+}
+impl_marker!(NoMirror);
+impl_marker!(MirrorPaused);
+
+/// What to do when the mirror component is removed from an entity
+/// that still has the component it mirrors.
+///
+/// Removing the mirrored component `T` always removes the mirror `U`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MirrorRemovalPolicy {
+    /// Do nothing, the entity keeps `T` without a mirror.
+    #[default]
+    Ignore,
+    /// Add back the mirror, with the current value of `T`.
+    Readd,
+    /// Remove `T` as well.
+    RemoveSource,
+}
+
+/// What to do when both `T` and its mirror `U` were edited since they were
+/// last kept in sync, for example when gameplay code writes `T` in the same
+/// frame as an inspector writes `U`.
+///
+/// This only matters with [`MirrorMode::Bidirectional`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MirrorConflictPolicy {
+    /// Overwrite `U` with the value of `T`, the edit to `U` is lost.
+    #[default]
+    SourceWins,
+    /// Apply `U` to `T`, the edit to `T` is lost.
+    MirrorWins,
+    /// Like [`MirrorConflictPolicy::SourceWins`], but also send a
+    /// [`MirrorConflict`] event with the lost value of `U`.
+    Report,
+}
+
+/// How often [`MirrorPlugin`] updates `U` with changes to `T`.
+///
+/// Edits to `U` are always applied to `T` right away. Only the fields of `U`
+/// edited since it was last updated are applied, so changes to `T` that are
+/// not in `U` yet are kept.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MirrorSampling {
+    /// Update `U` each frame `T` changes.
+    #[default]
+    EveryFrame,
+    /// Update `U` every given number of frames, with all changes to `T`
+    /// since the last update.
+    Frames(u32),
+    /// Update `U` at most once per given duration of real time, with all
+    /// changes to `T` since the last update.
+    ///
+    /// This samples every frame if there is no [`Time<Real>`] resource.
+    Interval(Duration),
+}
+
+/// In which direction [`MirrorPlugin`] keeps `T` and its mirror `U` in sync.
+///
+/// Whatever the mode, `U` is added to entities with `T`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MirrorMode {
+    /// Update `U` when `T` changes, and `T` when `U` changes.
+    #[default]
+    Bidirectional,
+    /// Only update `U` when `T` changes, edits to `U` are ignored.
+    ///
+    /// Use this for mirrors that display runtime state.
+    SourceToMirror,
+    /// Only update `T` when `U` changes, changes to `T` are not reflected in `U`.
+    ///
+    /// Use this for mirrors used to author `T`.
+    MirrorToSource,
+}
+impl MirrorMode {
+    pub(crate) const fn writes_mirror(self) -> bool {
+        matches!(self, Self::Bidirectional | Self::SourceToMirror)
+    }
+    pub(crate) const fn writes_source(self) -> bool {
+        matches!(self, Self::Bidirectional | Self::MirrorToSource)
+    }
+}
+
+type CreateSource<T, U> = for<'w, 's> fn(
+    &U,
+    &SystemParamItem<'w, 's, <U as ContextMirror<T>>::Param>,
+) -> Result<T, <U as ContextMirror<T>>::Error>;
+
+#[derive(Resource)]
+struct MirrorOptions<T, U: ContextMirror<T>> {
+    mode: MirrorMode,
+    conflict: MirrorConflictPolicy,
+    removal: MirrorRemovalPolicy,
+    sampling: MirrorSampling,
+    send_events: bool,
+    create_source: Option<CreateSource<T, U>>,
+    _types: PhantomData<fn() -> (T, U)>,
 }
 /// Update each frame [`Component`] `U` with the value of `T` and vis-versa.
 ///
 /// This will add `U` to [`Entity`] with the `T` components, and keep it updated,
-/// in [`MirrorSystems::Update`], in [`First`].
+/// in [`MirrorSystems::Update`], in [`First`] by default.
+/// All systems are also part of [`MirrorSet<T>`].
+///
+/// It will also add `U` to the type registry, with [`ReflectMirror`](crate::ReflectMirror)
+/// type data, and to the [`MirrorRegistry`](crate::MirrorRegistry).
+///
+/// Values are compared with [`Reflect::reflect_partial_eq`] before being
+/// written, so accessing `U` mutably without changing it doesn't touch `T`.
+/// Only the fields of `U` that changed are passed to `T`, see [`MirrorDiff`].
+/// Consider adding `#[reflect(PartialEq)]` to `U` if it implements `PartialEq`.
+///
+/// Use [`MirrorPlugin::mode`] to only sync in one direction.
 ///
-/// It will also add `T` to the type registry.
+/// An edit to either `T` or `U` is propagated once to the other side, it is
+/// not echoed back. When both change in the same frame, `T` wins, unless
+/// configured otherwise with [`MirrorPlugin::conflict_policy`].
+/// Use [`MirrorPlugin::sampling`] to update `U` less often than each frame.
 ///
-/// See [`Mirror`] for usage.
+/// When `T` is removed from an entity, `U` is removed as well. What happens
+/// when `U` is removed is controlled by [`MirrorPlugin::removal_policy`].
+///
+/// See [`Mirror`] for usage, [`TryMirror`] for mirrors that may fail
+/// to apply, and [`ContextMirror`] for mirrors that need to access the world.
+///
+/// Use [`MirrorPlugin::filter`] to only mirror some entities, and the [`NoMirror`]
+/// and [`MirrorPaused`] components to turn off mirroring of a single entity.
+/// Mirroring can be turned off at runtime with [`MirrorSettings`].
 ///
 /// If you only care for rapier components, see [`crate::RapierMirrorsPlugins`].
-pub struct MirrorPlugin<T: Component, U: Mirror<T> + Component + GetTypeRegistration>(
-    PhantomData<(T, U)>,
-);
-impl<T: Component, U: Mirror<T> + Component + GetTypeRegistration> MirrorPlugin<T, U> {
+pub struct MirrorPlugin<
+    T: Component,
+    U: ContextMirror<T> + Component + Reflect + GetTypeRegistration,
+    F: ReadOnlyWorldQuery = (),
+> {
+    mode: MirrorMode,
+    conflict: MirrorConflictPolicy,
+    removal: MirrorRemovalPolicy,
+    sampling: MirrorSampling,
+    send_events: bool,
+    create_source: Option<CreateSource<T, U>>,
+    update_schedule: Option<InternedScheduleLabel>,
+    add_schedule: Option<InternedScheduleLabel>,
+    same_frame: bool,
+    #[allow(clippy::type_complexity)]
+    _types: PhantomData<fn() -> (T, U, F)>,
+}
+impl<T, U, F> MirrorPlugin<T, U, F>
+where
+    T: Component,
+    U: ContextMirror<T> + Component + Reflect + GetTypeRegistration,
+    F: ReadOnlyWorldQuery,
+{
     pub const fn new() -> Self {
-        Self(PhantomData)
+        Self {
+            mode: MirrorMode::Bidirectional,
+            conflict: MirrorConflictPolicy::SourceWins,
+            removal: MirrorRemovalPolicy::Ignore,
+            sampling: MirrorSampling::EveryFrame,
+            send_events: false,
+            create_source: None,
+            update_schedule: None,
+            add_schedule: None,
+            same_frame: false,
+            _types: PhantomData,
+        }
+    }
+    /// Set the schedule in which `T` and `U` are kept in sync,
+    /// [`MirrorSystems::Update`].
+    ///
+    /// By default, this is [`First`].
+    pub fn update_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.update_schedule = Some(schedule.intern());
+        self
+    }
+    /// Set the schedule in which `U` is added and removed,
+    /// [`MirrorSystems::Add`] and [`MirrorSystems::Remove`].
+    ///
+    /// By default, this is [`Last`].
+    pub fn add_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.add_schedule = Some(schedule.intern());
+        self
+    }
+    /// Also add `U` in the [`MirrorInsertion`] schedule, so that `T` added
+    /// during [`Startup`] or [`Update`] have their mirror in [`PostUpdate`]
+    /// of the same frame.
+    ///
+    /// By default, `U` is only added in [`MirrorPlugin::add_schedule`],
+    /// which means a frame late with the default [`Last`].
+    pub const fn insert_same_frame(mut self) -> Self {
+        self.same_frame = true;
+        self
+    }
+    /// Set in which direction `T` and `U` are kept in sync.
+    ///
+    /// By default, this is [`MirrorMode::Bidirectional`].
+    pub const fn mode(mut self, mode: MirrorMode) -> Self {
+        self.mode = mode;
+        self
+    }
+    /// Set what to do when both `T` and `U` were edited since the last update.
+    ///
+    /// By default, this is [`MirrorConflictPolicy::SourceWins`].
+    pub const fn conflict_policy(mut self, policy: MirrorConflictPolicy) -> Self {
+        self.conflict = policy;
+        self
+    }
+    /// Set how often `U` is updated with changes to `T`, for example to not
+    /// update `U` each frame when `T` is rewritten each frame by a physics engine.
+    ///
+    /// By default, this is [`MirrorSampling::EveryFrame`].
+    pub const fn sampling(mut self, sampling: MirrorSampling) -> Self {
+        self.sampling = sampling;
+        self
+    }
+    /// Send [`MirrorApplied<T>`] events when `U` is applied to `T`, and
+    /// [`MirrorRefreshed<T>`] events when `U` is updated with the value of `T`.
+    ///
+    /// By default, no events are sent.
+    pub const fn send_events(mut self) -> Self {
+        self.send_events = true;
+        self
+    }
+    /// Add `T` to entities that have `U` but not `T`, using [`FromMirror`].
+    ///
+    /// By default, `U` without `T` is left alone. This has no effect
+    /// with [`MirrorMode::SourceToMirror`].
+    pub const fn create_source(mut self) -> Self
+    where
+        T: FromMirror<U>,
+    {
+        self.create_source = Some(T::from_mirror);
+        self
+    }
+    /// Set what to do when `U` is removed from an entity that still has `T`.
+    ///
+    /// By default, this is [`MirrorRemovalPolicy::Ignore`].
+    /// [`MirrorRemovalPolicy::RemoveSource`] has no effect
+    /// with [`MirrorMode::SourceToMirror`].
+    pub const fn removal_policy(mut self, policy: MirrorRemovalPolicy) -> Self {
+        self.removal = policy;
+        self
+    }
+    /// Only mirror entities matching the query filter `G`,
+    /// such as `With<Tunable>` or `Without<Static>`.
+    ///
+    /// This is the same as setting the `F` type parameter of [`MirrorPlugin`].
+    ///
+    /// `G` is checked each frame in [`MirrorSystems::Add`]: `U` is added to
+    /// entities with `T` once they match `G`, and removed from entities that
+    /// stop matching it, regardless of [`MirrorRemovalPolicy`].
+    ///
+    /// By default, all entities with `T` are mirrored.
+    pub const fn filter<G: ReadOnlyWorldQuery>(self) -> MirrorPlugin<T, U, G> {
+        MirrorPlugin {
+            mode: self.mode,
+            conflict: self.conflict,
+            removal: self.removal,
+            sampling: self.sampling,
+            send_events: self.send_events,
+            create_source: self.create_source,
+            update_schedule: self.update_schedule,
+            add_schedule: self.add_schedule,
+            same_frame: self.same_frame,
+            _types: PhantomData,
+        }
     }
 }
 
-impl<T: Component, U: Mirror<T> + Component + GetTypeRegistration> Default for MirrorPlugin<T, U> {
+impl<T, U, F> Default for MirrorPlugin<T, U, F>
+where
+    T: Component,
+    U: ContextMirror<T> + Component + Reflect + GetTypeRegistration,
+    F: ReadOnlyWorldQuery,
+{
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<T: Component, U: Mirror<T> + Component + GetTypeRegistration> Plugin for MirrorPlugin<T, U> {
+impl<T, U, F> Plugin for MirrorPlugin<T, U, F>
+where
+    T: Component,
+    U: ContextMirror<T> + Component + Reflect + GetTypeRegistration,
+    F: ReadOnlyWorldQuery + 'static,
+{
     fn build(&self, app: &mut App) {
+        let update = self.update_schedule.unwrap_or_else(|| First.intern());
+        let add = self.add_schedule.unwrap_or_else(|| Last.intern());
+        let set = MirrorSet::<T>::new();
+        let enabled = mirror_enabled::<T>;
         app.register_type::<U>()
-            .add_systems(Last, reflect_mirror_add::<T, U>.in_set(MirrorSystems::Add))
+            .add_event::<MirrorError>()
+            .add_event::<MirrorConflict>()
+            .init_resource::<MirrorSettings>()
+            .init_resource::<MirrorSnapshots<T, U>>()
+            .insert_resource(MirrorOptions::<T, U> {
+                mode: self.mode,
+                conflict: self.conflict,
+                removal: self.removal,
+                sampling: self.sampling,
+                send_events: self.send_events,
+                create_source: self.create_source,
+                _types: PhantomData,
+            })
             .add_systems(
-                First,
-                reflect_mirror_component::<T, U>.in_set(MirrorSystems::Update),
+                add,
+                reflect_mirror_add::<T, U, F>
+                    .run_if(enabled)
+                    .in_set(MirrorSystems::Add)
+                    .in_set(set),
+            )
+            .add_systems(
+                add,
+                reflect_mirror_remove::<T, U, F>
+                    .in_set(MirrorSystems::Remove)
+                    .in_set(set),
+            )
+            .add_systems(
+                update,
+                reflect_mirror_component::<T, U, F>
+                    .run_if(enabled)
+                    .after(TimeSystem)
+                    .in_set(MirrorSystems::Update)
+                    .in_set(set),
+            );
+        register_mirror::<T, U>(app, self.mode);
+        if TypeId::of::<F>() != TypeId::of::<()>() {
+            app.add_systems(
+                add,
+                reflect_mirror_filter::<T, U, F>
+                    .run_if(enabled)
+                    .in_set(MirrorSystems::Add)
+                    .in_set(set),
+            );
+        }
+        if self.send_events {
+            app.add_event::<MirrorApplied<T>>()
+                .add_event::<MirrorRefreshed<T>>();
+        }
+        let create_source = self.create_source.is_some() && self.mode.writes_source();
+        if create_source {
+            app.add_systems(
+                add,
+                reflect_source_add::<T, U>
+                    .run_if(enabled)
+                    .in_set(MirrorSystems::Add)
+                    .in_set(set),
+            );
+        }
+        if self.same_frame {
+            init_insertion_schedule(app);
+            let insert = reflect_mirror_add::<T, U, F>.run_if(enabled);
+            app.add_systems(
+                MirrorInsertion,
+                insert.in_set(MirrorSystems::Add).in_set(set),
             );
+            if create_source {
+                let insert = reflect_source_add::<T, U>
+                    .run_if(enabled)
+                    .in_set(MirrorSystems::Add);
+                app.add_systems(MirrorInsertion, insert.in_set(set));
+            }
+        }
+    }
+}
+/// Add [`MirrorInsertion`] to the main schedule, if not already present.
+fn init_insertion_schedule(app: &mut App) {
+    let Some(mut order) = app.world.get_resource_mut::<MainScheduleOrder>() else {
+        return;
+    };
+    let labels = &order.labels;
+    if labels.iter().any(|label| (**label).eq(&MirrorInsertion)) {
+        return;
+    }
+    let spawn_scene = labels.iter().any(|label| (**label).eq(&SpawnScene));
+    if spawn_scene {
+        order.insert_after(SpawnScene, MirrorInsertion);
+    } else {
+        order.insert_after(Update, MirrorInsertion);
     }
 }
@@ -0,0 +1,316 @@
+use std::{fmt, marker::PhantomData};
+
+use bevy::{
+    ecs::{
+        query::{QueryItem, ReadOnlyWorldQuery, WorldQuery},
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+    },
+    prelude::*,
+    reflect::GetTypeRegistration,
+    utils::HashSet,
+};
+
+use crate::mirror::mirror_eq;
+use crate::registry::register_mirror;
+use crate::settings::{mirror_enabled, toggled, MirrorSettings};
+use crate::{MirrorDiff, MirrorError, MirrorMode, MirrorSet, MirrorSystems};
+
+/// A tuple of components mirrored together by a [`MultiMirror`].
+///
+/// This is implemented for tuples of 2 to 8 components.
+pub trait MirrorSources: Send + Sync + 'static {
+    /// Read all the components, `(&A, &B, …)`.
+    type Ref: ReadOnlyWorldQuery;
+    /// Write all the components, `(Mut<A>, Mut<B>, …)` as query item.
+    type Mut: WorldQuery;
+    /// Any of the components was added.
+    type Added: ReadOnlyWorldQuery;
+    /// Any of the components changed.
+    type Changed: ReadOnlyWorldQuery;
+    /// Any of the components is missing.
+    type Missing: ReadOnlyWorldQuery;
+
+    /// Read the components from their mutable query item.
+    fn as_ref<'a>(sources: &'a QueryItem<Self::Mut>) -> QueryItem<'a, Self::Ref>;
+}
+macro_rules! impl_mirror_sources {
+    ($($c:ident),*) => {
+        impl<$($c: Component),*> MirrorSources for ($($c,)*) {
+            type Ref = ($(&'static $c,)*);
+            type Mut = ($(&'static mut $c,)*);
+            type Added = Or<($(Added<$c>,)*)>;
+            type Changed = Or<($(Changed<$c>,)*)>;
+            type Missing = Or<($(Without<$c>,)*)>;
+
+            #[allow(non_snake_case)]
+            fn as_ref<'a>(sources: &'a QueryItem<Self::Mut>) -> QueryItem<'a, Self::Ref> {
+                let ($($c,)*) = sources;
+                ($(&**$c,)*)
+            }
+        }
+    };
+}
+impl_mirror_sources!(A, B);
+impl_mirror_sources!(A, B, C);
+impl_mirror_sources!(A, B, C, D);
+impl_mirror_sources!(A, B, C, D, E);
+impl_mirror_sources!(A, B, C, D, E, F);
+impl_mirror_sources!(A, B, C, D, E, F, G);
+impl_mirror_sources!(A, B, C, D, E, F, G, H);
+
+/// Mirror several components `S` at once, `S` is a tuple such as `(A, B)`.
+///
+/// This is [`TryMirror`](crate::TryMirror) for a group of components, for example
+/// to edit all physics properties of an entity in a single panel.
+///
+/// Only write the components affected by the changed fields of the mirror,
+/// so that the other components are not marked as changed.
+///
+/// ```rust
+/// use bevy_mod_component_mirror::{MirrorDiff, MultiMirror, MultiMirrorPlugin};
+/// use bevy::prelude::*;
+/// use std::convert::Infallible;
+///
+/// # #[derive(Component)] pub struct Friction(f32);
+/// # #[derive(Component)] pub struct Restitution(f32);
+/// #[derive(Component, Reflect)]
+/// pub struct SurfaceMirror {
+///   friction: f32,
+///   restitution: f32,
+/// }
+/// // (Friction, Restitution) → SurfaceMirror
+/// impl<'a> From<(&'a Friction, &'a Restitution)> for SurfaceMirror {
+///   fn from((friction, restitution): (&'a Friction, &'a Restitution)) -> Self {
+///     SurfaceMirror { friction: friction.0, restitution: restitution.0 }
+///   }
+/// }
+/// // SurfaceMirror → (Friction, Restitution)
+/// impl MultiMirror<(Friction, Restitution)> for SurfaceMirror {
+///   type Error = Infallible;
+///
+///   fn apply(
+///     &self,
+///     (mut friction, mut restitution): (Mut<Friction>, Mut<Restitution>),
+///     diff: &MirrorDiff,
+///   ) -> Result<(), Infallible> {
+///     if diff.is_changed("friction") {
+///       friction.0 = self.friction;
+///     }
+///     if diff.is_changed("restitution") {
+///       restitution.0 = self.restitution;
+///     }
+///     Ok(())
+///   }
+/// }
+///
+/// fn main() {
+///   let mut app = App::new();
+///   app.add_plugins(MultiMirrorPlugin::<(Friction, Restitution), SurfaceMirror>::new());
+/// }
+/// ```
+pub trait MultiMirror<S: MirrorSources>: for<'a> From<QueryItem<'a, S::Ref>> {
+    type Error: fmt::Display;
+
+    /// Write to `sources` the fields of `self` listed in `diff`.
+    ///
+    /// When this returns an error, a [`MirrorError`] is sent. `sources`
+    /// must then be left unchanged.
+    fn apply(&self, sources: QueryItem<S::Mut>, diff: &MirrorDiff) -> Result<(), Self::Error>;
+}
+
+#[derive(Resource)]
+struct MultiMirrorOptions<S, U> {
+    mode: MirrorMode,
+    _types: PhantomData<fn() -> (S, U)>,
+}
+
+#[allow(clippy::type_complexity)]
+fn multi_mirror_add<S: MirrorSources, U: MultiMirror<S> + Component>(
+    query: Query<(Entity, S::Ref), (S::Added, Without<U>)>,
+    mut cmds: Commands,
+) {
+    for (entity, added) in &query {
+        cmds.entity(entity).insert(U::from(added));
+    }
+}
+#[allow(clippy::type_complexity)]
+fn multi_mirror_remove<S: MirrorSources, U: MultiMirror<S> + Component>(
+    query: Query<Entity, (With<U>, S::Missing)>,
+    mut cmds: Commands,
+) {
+    for entity in &query {
+        cmds.entity(entity).remove::<U>();
+    }
+}
+#[allow(clippy::type_complexity)]
+fn multi_mirror_toggle<S: MirrorSources, U: MultiMirror<S> + Component>(
+    settings: Res<MirrorSettings>,
+    mut was_disabled: Local<bool>,
+    mirrors: Query<Entity, With<U>>,
+    sources: Query<(Entity, S::Ref), Without<U>>,
+    mut cmds: Commands,
+) {
+    match toggled::<S>(&settings, &mut was_disabled) {
+        Some(true) => {
+            for (entity, source) in &sources {
+                cmds.entity(entity).insert(U::from(source));
+            }
+        }
+        Some(false) => {
+            for entity in &mirrors {
+                cmds.entity(entity).remove::<U>();
+            }
+        }
+        None => {}
+    }
+}
+#[allow(clippy::type_complexity)]
+fn multi_mirror_component<S: MirrorSources, U: MultiMirror<S> + Component + Reflect>(
+    mut query: ParamSet<(
+        Query<(Entity, S::Ref, &mut U), S::Changed>,
+        Query<(Entity, &U, S::Mut), Changed<U>>,
+    )>,
+    options: Res<MultiMirrorOptions<S, U>>,
+    mut refreshed: Local<HashSet<Entity>>,
+    mut errors: EventWriter<MirrorError>,
+) {
+    let mode = options.mode;
+    refreshed.clear();
+    if mode.writes_mirror() {
+        for (entity, changed, mut to_update) in &mut query.p0() {
+            let new = U::from(changed);
+            if !mirror_eq(&*to_update, &new) {
+                *to_update = new;
+                refreshed.insert(entity);
+            }
+        }
+    }
+    if mode.writes_source() {
+        for (entity, changed, to_update) in &mut query.p1() {
+            // `changed` was just set from `S`: avoid echoing it back.
+            if refreshed.contains(&entity) {
+                continue;
+            }
+            // Inspectors may mutably access `changed` without editing it.
+            let diff = MirrorDiff::new(&U::from(S::as_ref(&to_update)), changed);
+            if diff.is_empty() {
+                continue;
+            }
+            if let Err(error) = changed.apply(to_update, &diff) {
+                errors.send(MirrorError::new::<S, U>(Some(entity), &error));
+            }
+        }
+    }
+}
+
+/// Update each frame [`Component`] `U` with the value of all components in `S`
+/// and vis-versa, see [`MultiMirror`].
+///
+/// This will add `U` to [`Entity`] with all the components in `S`, and remove it
+/// from entities missing any of them. This happens in [`MirrorSystems`] and
+/// in [`MirrorSet<S>`], and can be turned off with [`MirrorSettings`].
+///
+/// Values are compared with [`Reflect::reflect_partial_eq`] before being
+/// written. When `U` changes, [`MultiMirror::apply`] is called with all
+/// components of `S` and the fields of `U` that changed, see [`MirrorDiff`].
+/// Edits to `S` are not echoed back to `S`, and when both `S` and `U` change
+/// in the same frame, `S` wins.
+///
+/// Unlike [`MirrorPlugin`](crate::MirrorPlugin), this doesn't support:
+///
+/// - conflict, removal and sampling options, or [`MirrorConflict`](crate::MirrorConflict) events,
+/// - [`MirrorApplied`](crate::MirrorApplied) and [`MirrorRefreshed`](crate::MirrorRefreshed) events,
+/// - recording edits in [`MirrorHistory`](crate::MirrorHistory),
+/// - [`NoMirror`](crate::NoMirror), [`MirrorPaused`](crate::MirrorPaused) and query filters,
+/// - creating `S` from `U`.
+pub struct MultiMirrorPlugin<
+    S: MirrorSources,
+    U: MultiMirror<S> + Component + Reflect + GetTypeRegistration,
+> {
+    mode: MirrorMode,
+    update_schedule: Option<InternedScheduleLabel>,
+    add_schedule: Option<InternedScheduleLabel>,
+    _types: PhantomData<fn() -> (S, U)>,
+}
+impl<S: MirrorSources, U: MultiMirror<S> + Component + Reflect + GetTypeRegistration>
+    MultiMirrorPlugin<S, U>
+{
+    pub const fn new() -> Self {
+        Self {
+            mode: MirrorMode::Bidirectional,
+            update_schedule: None,
+            add_schedule: None,
+            _types: PhantomData,
+        }
+    }
+    /// Set the schedule in which `S` and `U` are kept in sync,
+    /// [`MirrorSystems::Update`].
+    ///
+    /// By default, this is [`First`].
+    pub fn update_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.update_schedule = Some(schedule.intern());
+        self
+    }
+    /// Set the schedule in which `U` is added and removed,
+    /// [`MirrorSystems::Add`] and [`MirrorSystems::Remove`].
+    ///
+    /// By default, this is [`Last`].
+    pub fn add_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.add_schedule = Some(schedule.intern());
+        self
+    }
+    /// Set in which direction `S` and `U` are kept in sync.
+    ///
+    /// By default, this is [`MirrorMode::Bidirectional`].
+    pub const fn mode(mut self, mode: MirrorMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+impl<S: MirrorSources, U: MultiMirror<S> + Component + Reflect + GetTypeRegistration> Default
+    for MultiMirrorPlugin<S, U>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: MirrorSources, U: MultiMirror<S> + Component + Reflect + GetTypeRegistration> Plugin
+    for MultiMirrorPlugin<S, U>
+{
+    fn build(&self, app: &mut App) {
+        let update = self.update_schedule.unwrap_or_else(|| First.intern());
+        let add = self.add_schedule.unwrap_or_else(|| Last.intern());
+        let set = MirrorSet::<S>::new();
+        let enabled = mirror_enabled::<S>;
+        app.register_type::<U>()
+            .add_event::<MirrorError>()
+            .init_resource::<MirrorSettings>()
+            .insert_resource(MultiMirrorOptions::<S, U> {
+                mode: self.mode,
+                _types: PhantomData,
+            })
+            .add_systems(
+                add,
+                multi_mirror_add::<S, U>
+                    .run_if(enabled)
+                    .in_set(MirrorSystems::Add)
+                    .in_set(set),
+            )
+            .add_systems(
+                add,
+                (multi_mirror_toggle::<S, U>, multi_mirror_remove::<S, U>)
+                    .in_set(MirrorSystems::Remove)
+                    .in_set(set),
+            )
+            .add_systems(
+                update,
+                multi_mirror_component::<S, U>
+                    .run_if(enabled)
+                    .in_set(MirrorSystems::Update)
+                    .in_set(set),
+            );
+        register_mirror::<S, U>(app, self.mode);
+    }
+}
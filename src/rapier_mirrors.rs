@@ -10,8 +10,11 @@ use bevy_rapier3d::prelude::{
     AdditionalMassProperties, Collider, ColliderMassProperties, ImpulseJoint,
 };
 
-pub use collider::{AdditionalMassPropertiesMirror, ColliderMassPropertiesMirror, ColliderMirror};
-pub use impulse_joint::ImpulseJointMirror;
+pub use collider::{
+    AdditionalMassPropertiesMirror, ColliderMassPropertiesMirror, ColliderMirror, InvalidMass,
+    InvalidShape,
+};
+pub use impulse_joint::{ImpulseJointMirror, InvalidJoint};
 
 use self::{
     collider::{Compound, CompoundShapeElement},
@@ -45,6 +48,22 @@ pub type AdditionalMassPropertiesMirrorPlugin =
 /// - `Collider` (**some collider shape are not implemented yet!**)
 /// - `ColliderMassProperties`
 /// - `AdditionalMassProperties`
+///
+/// To also add the rapier component to entities with only its `XyzMirror`
+/// component, for example spawned from a scene, replace its plugin with one
+/// using [`MirrorPlugin::create_source`]:
+///
+/// ```rust
+/// use bevy_mod_component_mirror::rapier_mirrors::ColliderMirrorPlugin;
+/// use bevy_mod_component_mirror::RapierMirrorsPlugins;
+/// use bevy::prelude::*;
+/// # fn main() {
+/// # let mut app = App::new();
+/// app.add_plugins(
+///   RapierMirrorsPlugins.build().set(ColliderMirrorPlugin::new().create_source()),
+/// );
+/// # }
+/// ```
 pub struct RapierMirrorsPlugins;
 
 struct AdditionalReflectionsPlugin;
@@ -1,4 +1,9 @@
-use bevy::prelude::*;
+use std::{fmt, sync::Arc};
+
+use bevy::{
+    prelude::*,
+    reflect::{impl_type_path, Enum, ReflectRef},
+};
 use bevy_rapier3d::{
     prelude::{
         AdditionalMassProperties, Collider, ColliderMassProperties,
@@ -11,17 +16,26 @@ use bevy_rapier3d::{
     },
 };
 
-use crate::Mirror;
+use crate::{FromMirror, TryMirror};
 
-#[derive(Clone)]
-pub struct ShapeHolder(SharedShape);
-impl Default for ShapeHolder {
-    fn default() -> Self {
-        Self(SharedShape::ball(1.0))
+/// A rapier shape that can't be mirrored yet.
+///
+/// This is `None` when the shape was lost, for example when loading
+/// the mirror from a scene, since the rapier shape isn't reflected.
+#[derive(Clone, Default)]
+pub struct ShapeHolder(Option<SharedShape>);
+/// Shapes are immutable in rapier, so the same `Arc` means the same shape.
+impl PartialEq for ShapeHolder {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Some(left), Some(right)) => Arc::ptr_eq(&left.0, &right.0),
+            (None, None) => true,
+            _ => false,
+        }
     }
 }
 
-#[derive(Clone, Reflect, Default, Component)]
+#[derive(Clone, Reflect, Default, Component, PartialEq)]
 #[reflect(Default)]
 pub(super) struct CompoundShapeElement {
     offset: Vec3,
@@ -29,7 +43,7 @@ pub(super) struct CompoundShapeElement {
     shape: ColliderMirror,
 }
 
-#[derive(Clone, Reflect, Component)]
+#[derive(Clone, Reflect, Component, PartialEq)]
 #[reflect(Default)]
 pub struct Compound(Vec<CompoundShapeElement>);
 impl Default for Compound {
@@ -63,9 +77,9 @@ impl Compound {
     }
 }
 
-#[derive(Clone, Reflect)]
-#[reflect(Default)]
-#[reflect(from_reflect = false)]
+#[derive(Clone, Reflect, PartialEq)]
+#[reflect(Default, PartialEq, FromReflect)]
+#[reflect(from_reflect = false, type_path = false)]
 pub enum Shape {
     Ball {
         radius: f32,
@@ -106,10 +120,61 @@ pub enum Shape {
     UnimplementedYet(#[reflect(ignore)] ShapeHolder),
 }
 
+// `Shape` is recursive through `Compound`, the derived `TypePath` would
+// require itself to be implemented, so we implement it separately.
+impl_type_path!((in bevy_mod_component_mirror::rapier_mirrors::collider) Shape);
+
+fn variant_field<T: FromReflect>(value: &dyn Enum, name: &str) -> Option<T> {
+    T::from_reflect(value.field(name)?)
+}
+/// Build a `Shape` from any enum with the same variants, such as a `DynamicEnum`
+/// from a scene.
+///
+/// The rapier shape of `UnimplementedYet` is not reflected, so when building it
+/// from anything other than a `Shape`, the shape is missing. Such mirrors are
+/// rejected with [`InvalidShape::Unimplemented`] rather than applied.
 impl FromReflect for Shape {
     fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
-        let reflected = reflect.downcast_ref::<Self>()?;
-        Some(reflected.clone())
+        if let Some(reflected) = reflect.downcast_ref::<Self>() {
+            return Some(reflected.clone());
+        }
+        let ReflectRef::Enum(value) = reflect.reflect_ref() else {
+            return None;
+        };
+        let shape = match value.variant_name() {
+            "Ball" => Self::Ball {
+                radius: variant_field(value, "radius")?,
+            },
+            "Cuboid" => Self::Cuboid {
+                half_extents: variant_field(value, "half_extents")?,
+            },
+            "Capsule" => Self::Capsule {
+                a: variant_field(value, "a")?,
+                b: variant_field(value, "b")?,
+                radius: variant_field(value, "radius")?,
+            },
+            "Segment" => Self::Segment {
+                a: variant_field(value, "a")?,
+                b: variant_field(value, "b")?,
+            },
+            "Triangle" => Self::Triangle {
+                a: variant_field(value, "a")?,
+                b: variant_field(value, "b")?,
+                c: variant_field(value, "c")?,
+            },
+            "Compound" => Self::Compound(Compound::from_reflect(value.field_at(0)?)?),
+            "Cylinder" => Self::Cylinder {
+                half_height: variant_field(value, "half_height")?,
+                radius: variant_field(value, "radius")?,
+            },
+            "Cone" => Self::Cone {
+                half_height: variant_field(value, "half_height")?,
+                radius: variant_field(value, "radius")?,
+            },
+            "UnimplementedYet" => Self::UnimplementedYet(ShapeHolder::default()),
+            _ => return None,
+        };
+        Some(shape)
     }
 }
 
@@ -120,8 +185,8 @@ impl Default for Shape {
         }
     }
 }
-#[derive(Clone, Reflect, Component, Default)]
-#[reflect(Default)]
+#[derive(Clone, Reflect, Component, Default, PartialEq)]
+#[reflect(Component, Default, PartialEq)]
 pub struct ColliderMirror {
     pub shape: Shape,
     pub shape_rounded: Option<f32>,
@@ -185,7 +250,7 @@ impl<'a> From<&'a SharedShape> for Shape {
             | R::HeightField(_)
             | R::ConvexPolyhedron(_)
             | R::RoundConvexPolyhedron(_)
-            | R::Custom(_) => Self::UnimplementedYet(ShapeHolder(value.clone())),
+            | R::Custom(_) => Self::UnimplementedYet(ShapeHolder(Some(value.clone()))),
 
             R::Cylinder(v) => Self::Cylinder {
                 half_height: v.half_height,
@@ -220,6 +285,10 @@ impl<'a> From<&'a Collider> for ColliderMirror {
         Self::from(&value.raw)
     }
 }
+/// # Panics
+///
+/// If the mirror is an `UnimplementedYet` shape without its rapier shape,
+/// see [`InvalidShape::Unimplemented`].
 impl<'a> From<&'a ColliderMirror> for SharedShape {
     fn from(value: &'a ColliderMirror) -> Self {
         use Shape as S;
@@ -258,13 +327,125 @@ impl<'a> From<&'a ColliderMirror> for SharedShape {
                 radius,
             } => set_shape!(round Cone(half_height, radius)),
             S::Compound(ref elems) => set_shape!(Compound(elems.into_rapier())),
-            S::UnimplementedYet(ref shape) => shape.0.clone(),
+            S::UnimplementedYet(ref shape) => match &shape.0 {
+                Some(shape) => shape.clone(),
+                None => panic!("{}", InvalidShape::Unimplemented),
+            },
+        }
+    }
+}
+/// Why a [`ColliderMirror`] couldn't be applied to a [`Collider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidShape {
+    /// This length (radius, half extent…) is negative or not finite.
+    Length(&'static str),
+    /// This point or rotation is not finite.
+    NonFinite(&'static str),
+    /// A compound shape without any element.
+    EmptyCompound,
+    /// A compound shape containing another compound shape.
+    NestedCompound,
+    /// A shape that can't be mirrored yet, and whose rapier shape was lost,
+    /// for example when loading it from a scene.
+    Unimplemented,
+}
+impl fmt::Display for InvalidShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Length(field) => write!(f, "`{field}` must be positive and finite"),
+            Self::NonFinite(field) => write!(f, "`{field}` must be finite"),
+            Self::EmptyCompound => write!(f, "compound shapes need at least one element"),
+            Self::NestedCompound => write!(f, "compound shapes can't contain compound shapes"),
+            Self::Unimplemented => write!(f, "this shape can't be created from its mirror yet"),
         }
     }
 }
-impl Mirror<Collider> for ColliderMirror {
-    fn apply(&self, val: &mut Collider) {
+fn check_length(field: &'static str, value: f32) -> Result<(), InvalidShape> {
+    let valid = value.is_finite() && value >= 0.0;
+    valid.then_some(()).ok_or(InvalidShape::Length(field))
+}
+fn check_lengths(field: &'static str, value: Vec3) -> Result<(), InvalidShape> {
+    let valid = value.is_finite() && value.cmpge(Vec3::ZERO).all();
+    valid.then_some(()).ok_or(InvalidShape::Length(field))
+}
+fn check_finite(field: &'static str, value: Vec3) -> Result<(), InvalidShape> {
+    value
+        .is_finite()
+        .then_some(())
+        .ok_or(InvalidShape::NonFinite(field))
+}
+impl Shape {
+    fn validate(&self) -> Result<(), InvalidShape> {
+        match self {
+            Self::Ball { radius } => check_length("radius", *radius),
+            Self::Cuboid { half_extents } => check_lengths("half_extents", *half_extents),
+            Self::Capsule { a, b, radius } => {
+                check_finite("a", *a)?;
+                check_finite("b", *b)?;
+                check_length("radius", *radius)
+            }
+            Self::Segment { a, b } => {
+                check_finite("a", *a)?;
+                check_finite("b", *b)
+            }
+            Self::Triangle { a, b, c } => {
+                check_finite("a", *a)?;
+                check_finite("b", *b)?;
+                check_finite("c", *c)
+            }
+            Self::Compound(Compound(elems)) => {
+                if elems.is_empty() {
+                    return Err(InvalidShape::EmptyCompound);
+                }
+                for elem in elems {
+                    if let Self::Compound(_) = elem.shape.shape {
+                        return Err(InvalidShape::NestedCompound);
+                    }
+                    check_finite("offset", elem.offset)?;
+                    if !elem.rotation.is_finite() {
+                        return Err(InvalidShape::NonFinite("rotation"));
+                    }
+                    elem.shape.validate()?;
+                }
+                Ok(())
+            }
+            Self::Cylinder {
+                half_height,
+                radius,
+            }
+            | Self::Cone {
+                half_height,
+                radius,
+            } => {
+                check_length("half_height", *half_height)?;
+                check_length("radius", *radius)
+            }
+            Self::UnimplementedYet(ShapeHolder(Some(_))) => Ok(()),
+            Self::UnimplementedYet(ShapeHolder(None)) => Err(InvalidShape::Unimplemented),
+        }
+    }
+}
+impl ColliderMirror {
+    fn validate(&self) -> Result<(), InvalidShape> {
+        if let Some(rounded) = self.shape_rounded {
+            check_length("shape_rounded", rounded)?;
+        }
+        self.shape.validate()
+    }
+}
+impl TryMirror<Collider> for ColliderMirror {
+    type Error = InvalidShape;
+
+    fn try_apply(&self, val: &mut Collider) -> Result<(), Self::Error> {
+        self.validate()?;
         val.raw = self.into();
+        Ok(())
+    }
+}
+impl FromMirror<ColliderMirror> for Collider {
+    fn from_mirror(mirror: &ColliderMirror, (): &()) -> Result<Self, InvalidShape> {
+        mirror.validate()?;
+        Ok(SharedShape::from(mirror).into())
     }
 }
 #[derive(Clone, Reflect, Debug)]
@@ -286,18 +467,31 @@ impl MassProps {
     }
 }
 #[derive(Clone, Reflect, Debug, Component)]
+#[reflect(Component, Default)]
 pub enum AdditionalMassPropertiesMirror {
     Mass(f32),
     Props(MassProps),
 }
 
 #[derive(Clone, Reflect, Debug, Component)]
+#[reflect(Component, Default)]
 pub enum ColliderMassPropertiesMirror {
     Density(f32),
     Mass(f32),
     Props(MassProps),
 }
 
+impl Default for AdditionalMassPropertiesMirror {
+    fn default() -> Self {
+        Self::from(&AdditionalMassProperties::default())
+    }
+}
+impl Default for ColliderMassPropertiesMirror {
+    fn default() -> Self {
+        Self::from(&ColliderMassProperties::default())
+    }
+}
+
 impl<'a> From<&'a RapierMassProperties> for MassProps {
     fn from(value: &'a RapierMassProperties) -> Self {
         Self {
@@ -327,22 +521,93 @@ impl<'a> From<&'a ColliderMassProperties> for ColliderMassPropertiesMirror {
         }
     }
 }
-impl Mirror<AdditionalMassProperties> for AdditionalMassPropertiesMirror {
-    fn apply(&self, val: &mut AdditionalMassProperties) {
+/// Why a mass properties mirror couldn't be applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidMass {
+    /// This value is negative or not finite.
+    Value(&'static str),
+    /// The inertia frame rotation is not finite.
+    NonFiniteFrame,
+}
+impl fmt::Display for InvalidMass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Value(field) => write!(f, "`{field}` must be positive and finite"),
+            Self::NonFiniteFrame => write!(f, "`inertia_local_frame` must be finite"),
+        }
+    }
+}
+fn check_mass(field: &'static str, value: f32) -> Result<(), InvalidMass> {
+    let valid = value.is_finite() && value >= 0.0;
+    valid.then_some(()).ok_or(InvalidMass::Value(field))
+}
+impl MassProps {
+    fn validate(&self) -> Result<(), InvalidMass> {
+        check_mass("mass", self.mass)?;
+        if !self.local_center_of_mass.is_finite() {
+            return Err(InvalidMass::Value("local_center_of_mass"));
+        }
+        let inertia = self.principal_inertia;
+        if !inertia.is_finite() || !inertia.cmpge(Vec3::ZERO).all() {
+            return Err(InvalidMass::Value("principal_inertia"));
+        }
+        if !self.inertia_local_frame.is_finite() {
+            return Err(InvalidMass::NonFiniteFrame);
+        }
+        Ok(())
+    }
+}
+impl TryMirror<AdditionalMassProperties> for AdditionalMassPropertiesMirror {
+    type Error = InvalidMass;
+
+    fn try_apply(&self, val: &mut AdditionalMassProperties) -> Result<(), Self::Error> {
         use AdditionalMassProperties as Rapier;
         *val = match self {
-            Self::Mass(value) => Rapier::Mass(*value),
-            Self::Props(value) => Rapier::MassProperties(value.into_rapier()),
+            Self::Mass(value) => {
+                check_mass("mass", *value)?;
+                Rapier::Mass(*value)
+            }
+            Self::Props(value) => {
+                value.validate()?;
+                Rapier::MassProperties(value.into_rapier())
+            }
         };
+        Ok(())
     }
 }
-impl Mirror<ColliderMassProperties> for ColliderMassPropertiesMirror {
-    fn apply(&self, val: &mut ColliderMassProperties) {
+impl TryMirror<ColliderMassProperties> for ColliderMassPropertiesMirror {
+    type Error = InvalidMass;
+
+    fn try_apply(&self, val: &mut ColliderMassProperties) -> Result<(), Self::Error> {
         use ColliderMassProperties as Rapier;
         *val = match self {
-            Self::Density(value) => Rapier::Density(*value),
-            Self::Mass(value) => Rapier::Mass(*value),
-            Self::Props(value) => Rapier::MassProperties(value.into_rapier()),
+            Self::Density(value) => {
+                check_mass("density", *value)?;
+                Rapier::Density(*value)
+            }
+            Self::Mass(value) => {
+                check_mass("mass", *value)?;
+                Rapier::Mass(*value)
+            }
+            Self::Props(value) => {
+                value.validate()?;
+                Rapier::MassProperties(value.into_rapier())
+            }
         };
+        Ok(())
+    }
+}
+impl FromMirror<AdditionalMassPropertiesMirror> for AdditionalMassProperties {
+    fn from_mirror(mirror: &AdditionalMassPropertiesMirror, (): &()) -> Result<Self, InvalidMass> {
+        let mut value = Self::default();
+        mirror.try_apply(&mut value)?;
+        Ok(value)
+    }
+}
+impl FromMirror<ColliderMassPropertiesMirror> for ColliderMassProperties {
+    fn from_mirror(mirror: &ColliderMassPropertiesMirror, (): &()) -> Result<Self, InvalidMass> {
+        let mut value = Self::default();
+        mirror.try_apply(&mut value)?;
+        Ok(value)
     }
 }
@@ -1,4 +1,12 @@
-use bevy::prelude::*;
+use std::fmt;
+
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+};
 use bevy_rapier3d::{
     prelude::{GenericJoint, ImpulseJoint},
     rapier::prelude::{
@@ -7,10 +15,10 @@ use bevy_rapier3d::{
     },
 };
 
-use crate::Mirror;
+use crate::{FromMirror, MirrorDiff, TryMirror};
 
 /// The spring-like model used for constraints resolution.
-#[derive(Reflect, Default)]
+#[derive(Reflect, Default, Clone, Copy, PartialEq, Eq)]
 #[reflect(Default)]
 pub(super) enum MotorModel {
     /// The solved spring-like equation is:
@@ -29,6 +37,14 @@ impl From<RapierMotorModel> for MotorModel {
         }
     }
 }
+impl From<MotorModel> for RapierMotorModel {
+    fn from(value: MotorModel) -> Self {
+        match value {
+            MotorModel::AccelerationBased => Self::AccelerationBased,
+            MotorModel::ForceBased => Self::ForceBased,
+        }
+    }
+}
 #[derive(Reflect, Default)]
 #[reflect(Default)]
 pub(super) struct Frame {
@@ -49,7 +65,10 @@ impl Frame {
         }
     }
 }
-#[derive(Reflect, Default)]
+/// The motors, limits and locks of the three linear or angular axes of a joint.
+///
+/// Motor fields are only used by the axes in `motor_active`.
+#[derive(Reflect)]
 #[reflect(Default)]
 pub(super) struct JointMotor {
     target_vel: Vec3,
@@ -57,103 +76,93 @@ pub(super) struct JointMotor {
     stiffness: Vec3,
     damping: Vec3,
     impulse: Vec3,
+    #[reflect(default = "unlimited_force")]
+    max_force: Vec3,
+    motor_active: BVec3,
     locked: BVec3,
     limit_min: Vec3,
     limit_max: Vec3,
     limit_active: BVec3,
     model: MotorModel,
-    #[reflect(ignore)]
-    max_force: Vec3,
 }
-type LimitsFun = fn(&JointLimits<f32>) -> f32;
+/// The default rapier motor `max_force`, used when it is missing from
+/// a reflected [`JointMotor`].
+fn unlimited_force() -> Vec3 {
+    Vec3::splat(f32::MAX)
+}
+impl Default for JointMotor {
+    fn default() -> Self {
+        Self::new(&GenericJoint::default(), LINEAR)
+    }
+}
+const LINEAR: [JointAxis; 3] = [JointAxis::X, JointAxis::Y, JointAxis::Z];
+const ANGULAR: [JointAxis; 3] = [JointAxis::AngX, JointAxis::AngY, JointAxis::AngZ];
 impl JointMotor {
-    fn from_linear(joint: &GenericJoint) -> Option<Self> {
-        let mk_vec3 = |f: fn(&RapierJointMotor) -> f32| {
-            Some(Vec3 {
-                x: f(joint.motor(JointAxis::X)?),
-                y: f(joint.motor(JointAxis::Y)?),
-                z: f(joint.motor(JointAxis::Z)?),
-            })
-        };
-        let mk_vec3_limit = |f: LimitsFun| Vec3 {
-            x: joint.limits(JointAxis::X).map_or(0., f),
-            y: joint.limits(JointAxis::Y).map_or(0., f),
-            z: joint.limits(JointAxis::Z).map_or(0., f),
+    fn new(joint: &GenericJoint, [x, y, z]: [JointAxis; 3]) -> Self {
+        let motor = |f: fn(&RapierJointMotor) -> f32| Vec3 {
+            x: f(&joint.raw.motors[x as usize]),
+            y: f(&joint.raw.motors[y as usize]),
+            z: f(&joint.raw.motors[z as usize]),
         };
-        Some(Self {
-            target_vel: mk_vec3(|m| m.target_vel)?,
-            target_pos: mk_vec3(|m| m.target_pos)?,
-            stiffness: mk_vec3(|m| m.stiffness)?,
-            damping: mk_vec3(|m| m.damping)?,
-            max_force: mk_vec3(|m| m.max_force)?,
-            impulse: mk_vec3(|m| m.impulse)?,
-            locked: BVec3 {
-                x: joint.locked_axes().contains(JointAxis::X.into()),
-                y: joint.locked_axes().contains(JointAxis::Y.into()),
-                z: joint.locked_axes().contains(JointAxis::Z.into()),
-            },
-            limit_active: BVec3 {
-                x: joint.limits(JointAxis::X).is_some(),
-                y: joint.limits(JointAxis::Y).is_some(),
-                z: joint.limits(JointAxis::Z).is_some(),
-            },
-            limit_min: mk_vec3_limit(|l| l.min),
-            limit_max: mk_vec3_limit(|l| l.max),
-            model: joint.motor(JointAxis::X)?.model.into(),
-        })
-    }
-    fn from_angular(joint: &GenericJoint) -> Option<Self> {
-        let mk_vec3 = |f: fn(&RapierJointMotor) -> f32| {
-            Some(Vec3 {
-                x: f(joint.motor(JointAxis::AngX)?),
-                y: f(joint.motor(JointAxis::AngY)?),
-                z: f(joint.motor(JointAxis::AngZ)?),
-            })
+        let limit = |f: fn(&JointLimits<f32>) -> f32| Vec3 {
+            x: f(&joint.raw.limits[x as usize]),
+            y: f(&joint.raw.limits[y as usize]),
+            z: f(&joint.raw.limits[z as usize]),
         };
-        let mk_vec3_limit = |f: LimitsFun| Vec3 {
-            x: joint.limits(JointAxis::AngX).map_or(0., f),
-            y: joint.limits(JointAxis::AngY).map_or(0., f),
-            z: joint.limits(JointAxis::AngZ).map_or(0., f),
+        let mask = |mask: JointAxesMask| BVec3 {
+            x: mask.contains(x.into()),
+            y: mask.contains(y.into()),
+            z: mask.contains(z.into()),
         };
-        Some(Self {
-            target_vel: mk_vec3(|m| m.target_vel)?,
-            target_pos: mk_vec3(|m| m.target_pos)?,
-            stiffness: mk_vec3(|m| m.stiffness)?,
-            damping: mk_vec3(|m| m.damping)?,
-            max_force: mk_vec3(|m| m.max_force)?,
-            impulse: mk_vec3(|m| m.impulse)?,
-            locked: BVec3 {
-                x: joint.locked_axes().contains(JointAxis::AngX.into()),
-                y: joint.locked_axes().contains(JointAxis::AngY.into()),
-                z: joint.locked_axes().contains(JointAxis::AngZ.into()),
-            },
-            limit_active: BVec3 {
-                x: joint.limits(JointAxis::AngX).is_some(),
-                y: joint.limits(JointAxis::AngY).is_some(),
-                z: joint.limits(JointAxis::AngZ).is_some(),
-            },
-            limit_min: mk_vec3_limit(|l| l.min),
-            limit_max: mk_vec3_limit(|l| l.max),
-            model: joint.motor(JointAxis::AngX)?.model.into(),
-        })
+        let motored = [x, y, z].into_iter().find_map(|axis| joint.motor(axis));
+        Self {
+            target_vel: motor(|m| m.target_vel),
+            target_pos: motor(|m| m.target_pos),
+            stiffness: motor(|m| m.stiffness),
+            damping: motor(|m| m.damping),
+            impulse: motor(|m| m.impulse),
+            max_force: motor(|m| m.max_force),
+            motor_active: mask(joint.raw.motor_axes),
+            locked: mask(joint.raw.locked_axes),
+            limit_min: limit(|l| l.min),
+            limit_max: limit(|l| l.max),
+            limit_active: mask(joint.raw.limit_axes),
+            model: motored
+                .map_or(joint.raw.motors[x as usize].model, |m| m.model)
+                .into(),
+        }
     }
 }
 
 #[derive(Reflect, Component)]
+#[reflect(Component, Default, MapEntities)]
 pub struct ImpulseJointMirror {
     parent: Entity,
-    angular: Option<JointMotor>,
-    linear: Option<JointMotor>,
+    angular: JointMotor,
+    linear: JointMotor,
     contacts: bool,
     local_frame1: Frame,
     local_frame2: Frame,
 }
+impl MapEntities for ImpulseJointMirror {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        self.parent = entity_mapper.get_or_reserve(self.parent);
+    }
+}
+impl Default for ImpulseJointMirror {
+    fn default() -> Self {
+        Self::from(&ImpulseJoint::new(
+            Entity::PLACEHOLDER,
+            GenericJoint::default(),
+        ))
+    }
+}
 impl<'a> From<&'a ImpulseJoint> for ImpulseJointMirror {
     fn from(value: &'a ImpulseJoint) -> Self {
         Self {
             parent: value.parent,
-            angular: JointMotor::from_angular(&value.data),
-            linear: JointMotor::from_linear(&value.data),
+            angular: JointMotor::new(&value.data, ANGULAR),
+            linear: JointMotor::new(&value.data, LINEAR),
             contacts: value.data.contacts_enabled(),
             local_frame1: Frame::from1(&value.data),
             local_frame2: Frame::from2(&value.data),
@@ -176,50 +185,212 @@ fn component_of_b(joint: JointAxis) -> fn(BVec3) -> bool {
         Z | AngZ => |v| v.z,
     }
 }
-impl Mirror<ImpulseJoint> for ImpulseJointMirror {
-    fn apply(&self, val: &mut ImpulseJoint) {
+/// Why an [`ImpulseJointMirror`] couldn't be applied to an [`ImpulseJoint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidJoint {
+    /// This value is not finite.
+    NonFinite(&'static str),
+    /// This value is negative.
+    Negative(&'static str),
+    /// A limit has its `limit_min` greater than its `limit_max`.
+    InvertedLimits,
+}
+impl fmt::Display for InvalidJoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonFinite(field) => write!(f, "`{field}` must be finite"),
+            Self::Negative(field) => write!(f, "`{field}` must be positive"),
+            Self::InvertedLimits => write!(f, "`limit_min` must not be greater than `limit_max`"),
+        }
+    }
+}
+fn check_finite(field: &'static str, value: Vec3) -> Result<(), InvalidJoint> {
+    value
+        .is_finite()
+        .then_some(())
+        .ok_or(InvalidJoint::NonFinite(field))
+}
+fn check_positive(field: &'static str, value: Vec3) -> Result<(), InvalidJoint> {
+    check_finite(field, value)?;
+    let valid = value.cmpge(Vec3::ZERO).all();
+    valid.then_some(()).ok_or(InvalidJoint::Negative(field))
+}
+impl Frame {
+    fn validate(&self) -> Result<(), InvalidJoint> {
+        if !self.basis.is_finite() {
+            return Err(InvalidJoint::NonFinite("basis"));
+        }
+        check_finite("anchor", self.anchor)
+    }
+}
+impl JointMotor {
+    fn validate(&self) -> Result<(), InvalidJoint> {
+        check_finite("target_vel", self.target_vel)?;
+        check_finite("target_pos", self.target_pos)?;
+        check_positive("stiffness", self.stiffness)?;
+        check_positive("damping", self.damping)?;
+        check_finite("impulse", self.impulse)?;
+        check_positive("max_force", self.max_force)?;
+        check_finite("limit_min", self.limit_min)?;
+        check_finite("limit_max", self.limit_max)?;
+        let inverted = self.limit_min.cmpgt(self.limit_max) & self.limit_active;
+        if inverted.any() {
+            return Err(InvalidJoint::InvertedLimits);
+        }
+        Ok(())
+    }
+}
+impl ImpulseJointMirror {
+    fn validate(&self) -> Result<(), InvalidJoint> {
+        self.local_frame1.validate()?;
+        self.local_frame2.validate()?;
+        self.linear.validate()?;
+        self.angular.validate()
+    }
+}
+const AXES: [(usize, JointAxis); 6] = {
+    use JointAxis::*;
+    [(0, X), (1, Y), (2, Z), (3, AngX), (4, AngY), (5, AngZ)]
+};
+impl JointMotor {
+    /// Set all values of `joint`'s `axis` to the ones in `self`.
+    fn apply_axis(&self, joint: &mut GenericJoint, (i, axis): (usize, JointAxis)) {
+        let c = component_of(axis);
+        let b = component_of_b(axis);
+        joint.set_motor(
+            axis,
+            c(self.target_pos),
+            c(self.target_vel),
+            c(self.stiffness),
+            c(self.damping),
+        );
+        if !b(self.motor_active) {
+            joint.raw.motor_axes &= !JointAxesMask::from(axis);
+        }
+        joint.raw.limit_axes &= !JointAxesMask::from(axis);
+        if b(self.limit_active) {
+            joint.raw.limit_axes |= axis.into();
+            joint.raw.limits[i].min = c(self.limit_min);
+            joint.raw.limits[i].max = c(self.limit_max);
+        }
+        joint.raw.motors[i].impulse = c(self.impulse);
+        joint.set_motor_model(axis, self.model.into());
+        joint.set_motor_max_force(axis, c(self.max_force));
+
+        joint.raw.locked_axes &= !JointAxesMask::from(axis);
+        if b(self.locked) {
+            joint.raw.locked_axes |= axis.into();
+        }
+    }
+    /// Set the values of `joint`'s `axis` that differ between `self` and `old`,
+    /// keeping the rest of the solver state, such as limit impulses.
+    fn apply_axis_diff(&self, old: &Self, joint: &mut GenericJoint, (i, axis): (usize, JointAxis)) {
+        let c = component_of(axis);
+        let b = component_of_b(axis);
+        let differs = |f: fn(&Self) -> Vec3| c(f(self)) != c(f(old));
+
+        let motor_changed = differs(|m| m.target_pos)
+            || differs(|m| m.target_vel)
+            || differs(|m| m.stiffness)
+            || differs(|m| m.damping);
+        if motor_changed {
+            joint.raw.motors[i].target_pos = c(self.target_pos);
+            joint.raw.motors[i].target_vel = c(self.target_vel);
+            joint.raw.motors[i].stiffness = c(self.stiffness);
+            joint.raw.motors[i].damping = c(self.damping);
+        }
+        if b(self.motor_active) != b(old.motor_active) {
+            joint.raw.motor_axes &= !JointAxesMask::from(axis);
+            if b(self.motor_active) {
+                joint.raw.motor_axes |= axis.into();
+            }
+        }
+        if differs(|m| m.impulse) {
+            joint.raw.motors[i].impulse = c(self.impulse);
+        }
+        if differs(|m| m.max_force) {
+            joint.set_motor_max_force(axis, c(self.max_force));
+        }
+        if self.model != old.model {
+            joint.set_motor_model(axis, self.model.into());
+        }
+        let limits_changed = b(self.limit_active) != b(old.limit_active)
+            || differs(|m| m.limit_min)
+            || differs(|m| m.limit_max);
+        if limits_changed {
+            joint.raw.limit_axes &= !JointAxesMask::from(axis);
+            if b(self.limit_active) {
+                joint.raw.limit_axes |= axis.into();
+                joint.raw.limits[i].min = c(self.limit_min);
+                joint.raw.limits[i].max = c(self.limit_max);
+            }
+        }
+        if b(self.locked) != b(old.locked) {
+            joint.raw.locked_axes &= !JointAxesMask::from(axis);
+            if b(self.locked) {
+                joint.raw.locked_axes |= axis.into();
+            }
+        }
+    }
+}
+impl ImpulseJointMirror {
+    fn motor(&self, axis: JointAxis) -> &JointMotor {
         use JointAxis::*;
+        match axis {
+            X | Y | Z => &self.linear,
+            AngX | AngY | AngZ => &self.angular,
+        }
+    }
+}
+impl TryMirror<ImpulseJoint> for ImpulseJointMirror {
+    type Error = InvalidJoint;
+
+    fn try_apply(&self, val: &mut ImpulseJoint) -> Result<(), Self::Error> {
+        self.validate()?;
         val.parent = self.parent;
+        val.data.set_contacts_enabled(self.contacts);
         val.data.set_local_basis1(self.local_frame1.basis);
         val.data.set_local_anchor1(self.local_frame1.anchor);
         val.data.set_local_basis2(self.local_frame2.basis);
         val.data.set_local_anchor2(self.local_frame2.anchor);
-        for (i, joint, source) in [
-            (0, X, &self.linear),
-            (1, Y, &self.linear),
-            (2, Z, &self.linear),
-            (3, AngX, &self.angular),
-            (4, AngY, &self.angular),
-            (5, AngZ, &self.angular),
-        ] {
-            let Some(source) = source else { continue };
-            let c = component_of(joint);
-            let b = component_of_b(joint);
-            val.data.set_motor(
-                joint,
-                c(source.target_pos),
-                c(source.target_vel),
-                c(source.stiffness),
-                c(source.damping),
-            );
-            val.data.raw.limit_axes &= !JointAxesMask::from(joint);
-            if b(source.limit_active) {
-                val.data.raw.limit_axes |= joint.into();
-            }
-            if b(source.limit_active) {
-                let (min, max) = (c(source.limit_min), c(source.limit_max));
-                val.data.raw.limits[i] = JointLimits {
-                    min,
-                    max,
-                    impulse: c(source.impulse),
-                };
-            }
-            val.data.set_motor_max_force(joint, c(source.max_force));
-
-            val.data.raw.locked_axes &= !JointAxesMask::from(joint);
-            if b(source.locked) {
-                val.data.raw.locked_axes |= joint.into();
+        for axis in AXES {
+            self.motor(axis.1).apply_axis(&mut val.data, axis);
+        }
+        Ok(())
+    }
+    fn try_apply_diff(&self, val: &mut ImpulseJoint, diff: &MirrorDiff) -> Result<(), Self::Error> {
+        self.validate()?;
+        let old = Self::from(&*val);
+        if diff.is_changed("parent") {
+            val.parent = self.parent;
+        }
+        if diff.is_changed("contacts") {
+            val.data.set_contacts_enabled(self.contacts);
+        }
+        if diff.is_changed("local_frame1") {
+            val.data.set_local_basis1(self.local_frame1.basis);
+            val.data.set_local_anchor1(self.local_frame1.anchor);
+        }
+        if diff.is_changed("local_frame2") {
+            val.data.set_local_basis2(self.local_frame2.basis);
+            val.data.set_local_anchor2(self.local_frame2.anchor);
+        }
+        let linear = diff.is_changed("linear");
+        let angular = diff.is_changed("angular");
+        for axis in AXES {
+            let changed = if axis.0 < 3 { linear } else { angular };
+            if changed {
+                let old = old.motor(axis.1);
+                self.motor(axis.1).apply_axis_diff(old, &mut val.data, axis);
             }
         }
+        Ok(())
+    }
+}
+impl FromMirror<ImpulseJointMirror> for ImpulseJoint {
+    fn from_mirror(mirror: &ImpulseJointMirror, (): &()) -> Result<Self, InvalidJoint> {
+        let mut value = ImpulseJoint::new(mirror.parent, GenericJoint::default());
+        mirror.try_apply(&mut value)?;
+        Ok(value)
     }
 }
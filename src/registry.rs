@@ -0,0 +1,86 @@
+use std::any::{type_name, TypeId};
+
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::MirrorMode;
+
+/// Type data registered by [`MirrorPlugin`](crate::MirrorPlugin) and
+/// [`MirrorResourcePlugin`](crate::MirrorResourcePlugin) on the mirror type `U`.
+///
+/// Tools can use it to find the type mirrored by `U`, for example to display
+/// `U` in place of the non-reflect `T`.
+///
+/// ```rust
+/// use bevy_mod_component_mirror::ReflectMirror;
+/// use bevy::prelude::*;
+///
+/// fn mirror_of(registry: &AppTypeRegistry, mirror: &dyn Reflect) -> Option<&'static str> {
+///   let registry = registry.read();
+///   let data = registry.get_type_data::<ReflectMirror>(mirror.type_id())?;
+///   Some(data.source_name)
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReflectMirror {
+    /// The [`TypeId`] of the mirrored type `T`.
+    pub source: TypeId,
+    /// The type name of the mirrored type `T`.
+    pub source_name: &'static str,
+    /// The [`TypeId`] of the mirror type `U`.
+    pub mirror: TypeId,
+    /// The type name of the mirror type `U`.
+    pub mirror_name: &'static str,
+    /// In which direction `T` and `U` are kept in sync.
+    pub mode: MirrorMode,
+}
+impl ReflectMirror {
+    fn new<T: 'static, U: 'static>(mode: MirrorMode) -> Self {
+        Self {
+            source: TypeId::of::<T>(),
+            source_name: type_name::<T>(),
+            mirror: TypeId::of::<U>(),
+            mirror_name: type_name::<U>(),
+            mode,
+        }
+    }
+}
+
+/// Every mirror added to the app, with [`ReflectMirror`] as description.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct MirrorRegistry {
+    by_mirror: HashMap<TypeId, ReflectMirror>,
+}
+impl MirrorRegistry {
+    /// The mirror `mirror` stands for, if it is a registered mirror type.
+    pub fn get(&self, mirror: TypeId) -> Option<&ReflectMirror> {
+        self.by_mirror.get(&mirror)
+    }
+    /// The mirrors of `source`.
+    pub fn mirrors_of(&self, source: TypeId) -> impl Iterator<Item = &ReflectMirror> {
+        self.iter().filter(move |data| data.source == source)
+    }
+    /// Whether `ty` is mirrored by any mirror.
+    pub fn is_mirrored(&self, ty: TypeId) -> bool {
+        self.mirrors_of(ty).next().is_some()
+    }
+    /// All registered mirrors.
+    pub fn iter(&self) -> impl Iterator<Item = &ReflectMirror> {
+        self.by_mirror.values()
+    }
+}
+
+/// Add the `U` mirror of `T` to [`MirrorRegistry`], and its [`ReflectMirror`]
+/// to the type registry.
+///
+/// `U` must already be registered.
+pub(crate) fn register_mirror<T: 'static, U: 'static>(app: &mut App, mode: MirrorMode) {
+    let data = ReflectMirror::new::<T, U>(mode);
+    let registry = app.world.resource::<AppTypeRegistry>();
+    if let Some(registration) = registry.write().get_mut(data.mirror) {
+        registration.insert(data);
+    }
+    let mut mirrors = app
+        .world
+        .get_resource_or_insert_with(MirrorRegistry::default);
+    mirrors.by_mirror.insert(data.mirror, data);
+}
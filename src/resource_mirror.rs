@@ -0,0 +1,246 @@
+use std::marker::PhantomData;
+
+use bevy::{
+    ecs::{
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+        system::SystemChangeTick,
+    },
+    prelude::*,
+    reflect::GetTypeRegistration,
+};
+
+use crate::history::{MirrorEdit, MirrorHistory};
+use crate::mirror::{apply_mirror, refresh_mirror, Refresh};
+use crate::registry::register_mirror;
+use crate::settings::{mirror_enabled, toggled, MirrorSettings};
+use crate::{
+    MirrorConflict, MirrorConflictPolicy, MirrorError, MirrorMode, MirrorSet, MirrorSystems,
+    TryMirror,
+};
+
+#[derive(Resource)]
+struct ResourceMirrorOptions<T, U> {
+    mode: MirrorMode,
+    conflict: MirrorConflictPolicy,
+    _types: PhantomData<fn() -> (T, U)>,
+}
+
+fn reflect_resource_add<T: Resource, U: TryMirror<T> + Resource>(
+    source: Option<Res<T>>,
+    mirror: Option<Res<U>>,
+    mut cmds: Commands,
+) {
+    if let (Some(source), None) = (source, mirror) {
+        if source.is_added() {
+            cmds.insert_resource(U::from(&*source));
+        }
+    }
+}
+fn reflect_resource_remove<T: Resource, U: TryMirror<T> + Resource>(
+    source: Option<Res<T>>,
+    mirror: Option<Res<U>>,
+    mut had_source: Local<bool>,
+    mut cmds: Commands,
+) {
+    let has_source = source.is_some();
+    if *had_source && !has_source && mirror.is_some() {
+        cmds.remove_resource::<U>();
+    }
+    *had_source = has_source;
+}
+fn reflect_resource_toggle<T: Resource, U: TryMirror<T> + Resource>(
+    settings: Res<MirrorSettings>,
+    mut was_disabled: Local<bool>,
+    source: Option<Res<T>>,
+    mut cmds: Commands,
+) {
+    match toggled::<T>(&settings, &mut was_disabled) {
+        Some(true) => {
+            if let Some(source) = source {
+                cmds.insert_resource(U::from(&*source));
+            }
+        }
+        Some(false) => cmds.remove_resource::<U>(),
+        None => {}
+    }
+}
+fn reflect_resource_mirror<T: Resource, U: TryMirror<T> + Resource + Reflect>(
+    source: Option<ResMut<T>>,
+    mirror: Option<ResMut<U>>,
+    options: Res<ResourceMirrorOptions<T, U>>,
+    history: Option<ResMut<MirrorHistory>>,
+    ticks: SystemChangeTick,
+    mut errors: EventWriter<MirrorError>,
+    mut conflicts: EventWriter<MirrorConflict>,
+) {
+    let (Some(mut source), Some(mut mirror)) = (source, mirror) else {
+        return;
+    };
+    let mode = options.mode;
+    let mut refreshed = false;
+    if mode.writes_mirror() && source.is_changed() {
+        match refresh_mirror(mode, options.conflict, &*source, mirror.reborrow(), &()) {
+            Refresh::Unchanged => {}
+            Refresh::Updated(_) => refreshed = true,
+            Refresh::Conflict(lost) => {
+                conflicts.send(MirrorConflict::new::<T, U>(None, lost));
+                refreshed = true;
+            }
+        }
+    }
+    // When `mirror` was just set from `source`, avoid echoing it back.
+    if mode.writes_source() && mirror.is_changed() && !refreshed {
+        match apply_mirror(&*mirror, None, source.reborrow(), &()) {
+            Ok(Some(before)) => {
+                if let Some(mut history) = history {
+                    history.push(MirrorEdit::resource::<T, U>(before, &mirror), &ticks);
+                }
+            }
+            Ok(None) => {}
+            Err(error) => errors.send(MirrorError::new::<T, U>(None, &error)),
+        }
+    }
+}
+
+/// Update each frame [`Resource`] `U` with the value of `T` and vis-versa.
+///
+/// This is the [`MirrorPlugin`](crate::MirrorPlugin) equivalent for resources,
+/// so that resource inspectors can edit `T` through `U`.
+/// Consider adding `#[reflect(Resource)]` to `U`.
+///
+/// `U` is inserted when `T` is inserted, and removed when `T` is removed.
+/// It is then kept in sync with the same rules as `MirrorPlugin`, in the same
+/// [`MirrorSystems`] sets and in [`MirrorSet<T>`].
+///
+/// `U` is added to the type registry with [`ReflectMirror`](crate::ReflectMirror)
+/// type data, and to the [`MirrorRegistry`](crate::MirrorRegistry).
+///
+/// ```rust
+/// use bevy_mod_component_mirror::{Mirror, MirrorResourcePlugin};
+/// use bevy::prelude::*;
+///
+/// #[derive(Resource)]
+/// pub struct Gravity(f32);
+///
+/// #[derive(Resource, Reflect, Default)]
+/// #[reflect(Resource)]
+/// pub struct GravityMirror {
+///   gravity: f32,
+/// }
+/// impl<'a> From<&'a Gravity> for GravityMirror {
+///   fn from(value: &'a Gravity) -> Self { GravityMirror { gravity: value.0 } }
+/// }
+/// impl Mirror<Gravity> for GravityMirror {
+///   fn apply(&self, value: &mut Gravity) { value.0 = self.gravity; }
+/// }
+///
+/// fn main() {
+///   let mut app = App::new();
+///   app.add_plugins(MirrorResourcePlugin::<Gravity, GravityMirror>::new());
+/// }
+/// ```
+pub struct MirrorResourcePlugin<
+    T: Resource,
+    U: TryMirror<T> + Resource + Reflect + GetTypeRegistration,
+> {
+    mode: MirrorMode,
+    conflict: MirrorConflictPolicy,
+    update_schedule: Option<InternedScheduleLabel>,
+    add_schedule: Option<InternedScheduleLabel>,
+    _types: PhantomData<fn() -> (T, U)>,
+}
+impl<T: Resource, U: TryMirror<T> + Resource + Reflect + GetTypeRegistration>
+    MirrorResourcePlugin<T, U>
+{
+    pub const fn new() -> Self {
+        Self {
+            mode: MirrorMode::Bidirectional,
+            conflict: MirrorConflictPolicy::SourceWins,
+            update_schedule: None,
+            add_schedule: None,
+            _types: PhantomData,
+        }
+    }
+    /// Set the schedule in which `T` and `U` are kept in sync,
+    /// [`MirrorSystems::Update`].
+    ///
+    /// By default, this is [`First`].
+    pub fn update_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.update_schedule = Some(schedule.intern());
+        self
+    }
+    /// Set the schedule in which `U` is inserted and removed,
+    /// [`MirrorSystems::Add`] and [`MirrorSystems::Remove`].
+    ///
+    /// By default, this is [`Last`].
+    pub fn add_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.add_schedule = Some(schedule.intern());
+        self
+    }
+    /// Set in which direction `T` and `U` are kept in sync.
+    ///
+    /// By default, this is [`MirrorMode::Bidirectional`].
+    pub const fn mode(mut self, mode: MirrorMode) -> Self {
+        self.mode = mode;
+        self
+    }
+    /// Set what to do when both `T` and `U` were edited since the last update.
+    ///
+    /// By default, this is [`MirrorConflictPolicy::SourceWins`].
+    pub const fn conflict_policy(mut self, policy: MirrorConflictPolicy) -> Self {
+        self.conflict = policy;
+        self
+    }
+}
+
+impl<T: Resource, U: TryMirror<T> + Resource + Reflect + GetTypeRegistration> Default
+    for MirrorResourcePlugin<T, U>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Resource, U: TryMirror<T> + Resource + Reflect + GetTypeRegistration> Plugin
+    for MirrorResourcePlugin<T, U>
+{
+    fn build(&self, app: &mut App) {
+        let update = self.update_schedule.unwrap_or_else(|| First.intern());
+        let add = self.add_schedule.unwrap_or_else(|| Last.intern());
+        let set = MirrorSet::<T>::new();
+        let enabled = mirror_enabled::<T>;
+        app.register_type::<U>()
+            .add_event::<MirrorError>()
+            .add_event::<MirrorConflict>()
+            .init_resource::<MirrorSettings>()
+            .insert_resource(ResourceMirrorOptions::<T, U> {
+                mode: self.mode,
+                conflict: self.conflict,
+                _types: PhantomData,
+            })
+            .add_systems(
+                add,
+                reflect_resource_add::<T, U>
+                    .run_if(enabled)
+                    .in_set(MirrorSystems::Add)
+                    .in_set(set),
+            )
+            .add_systems(
+                add,
+                (
+                    reflect_resource_toggle::<T, U>,
+                    reflect_resource_remove::<T, U>,
+                )
+                    .in_set(MirrorSystems::Remove)
+                    .in_set(set),
+            )
+            .add_systems(
+                update,
+                reflect_resource_mirror::<T, U>
+                    .run_if(enabled)
+                    .in_set(MirrorSystems::Update)
+                    .in_set(set),
+            );
+        register_mirror::<T, U>(app, self.mode);
+    }
+}
@@ -0,0 +1,80 @@
+use std::any::TypeId;
+
+use bevy::{prelude::*, utils::HashSet};
+
+/// Turn mirroring on and off at runtime, for all mirrors or per mirrored type.
+///
+/// When mirroring of `T` is off, the systems syncing `T` don't run, and
+/// the mirrors of `T` are removed. They are added back when it is turned on.
+///
+/// `T` is the mirrored type, such as `Collider`, or the tuple of components
+/// of a [`MultiMirrorPlugin`](crate::MultiMirrorPlugin).
+///
+/// ```rust
+/// use bevy_mod_component_mirror::MirrorSettings;
+/// use bevy::prelude::*;
+/// # #[derive(Component)] pub struct Foreign;
+///
+/// fn toggle_mirrors(mut settings: ResMut<MirrorSettings>, keys: Res<Input<KeyCode>>) {
+///   if keys.just_pressed(KeyCode::F1) {
+///     let enabled = settings.enabled();
+///     settings.set_enabled(!enabled);
+///   }
+///   if keys.just_pressed(KeyCode::F2) {
+///     settings.set_enabled_for::<Foreign>(false);
+///   }
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, Default)]
+pub struct MirrorSettings {
+    all_disabled: bool,
+    disabled: HashSet<TypeId>,
+}
+impl MirrorSettings {
+    /// Whether mirroring is on, this doesn't account for per-type settings.
+    pub fn enabled(&self) -> bool {
+        !self.all_disabled
+    }
+    /// Turn all mirroring on or off.
+    ///
+    /// Mirrors turned off with [`MirrorSettings::set_enabled_for`] stay off.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.all_disabled = !enabled;
+    }
+    /// Whether `T` is mirrored.
+    pub fn is_enabled<T: 'static>(&self) -> bool {
+        !self.all_disabled && !self.disabled.contains(&TypeId::of::<T>())
+    }
+    /// Turn mirroring of `T` on or off.
+    pub fn set_enabled_for<T: 'static>(&mut self, enabled: bool) {
+        if enabled {
+            self.disabled.remove(&TypeId::of::<T>());
+        } else {
+            self.disabled.insert(TypeId::of::<T>());
+        }
+    }
+}
+
+/// Run condition, whether `T` is mirrored.
+pub(crate) fn mirror_enabled<T: 'static>(settings: Res<MirrorSettings>) -> bool {
+    settings.is_enabled::<T>()
+}
+
+/// Whether mirroring of `T` was just turned on (`Some(true)`) or off (`Some(false)`).
+///
+/// `was_disabled` is the state last time this was called, `false` by default,
+/// as mirrors start enabled.
+pub(crate) fn toggled<T: 'static>(
+    settings: &Res<MirrorSettings>,
+    was_disabled: &mut bool,
+) -> Option<bool> {
+    if !settings.is_changed() {
+        return None;
+    }
+    let enabled = settings.is_enabled::<T>();
+    if enabled != *was_disabled {
+        return None;
+    }
+    *was_disabled = !enabled;
+    Some(enabled)
+}
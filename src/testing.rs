@@ -0,0 +1,311 @@
+//! Check that mirrors faithfully represent the values they mirror.
+//!
+//! This module is enabled with the `testing` feature.
+//!
+//! [`assert_mirror_roundtrip`] and [`MirrorRoundtrip`] check a single value,
+//! without an [`App`]. [`MirrorHarness`] runs a headless [`App`] to check how
+//! a mirror behaves once added with [`MirrorPlugin`](crate::MirrorPlugin).
+//!
+//! ```rust
+//! use bevy_mod_component_mirror::testing::{assert_mirror_roundtrip, MirrorHarness};
+//! use bevy_mod_component_mirror::{FromMirror, Mirror, MirrorPlugin};
+//! use bevy::prelude::*;
+//! use std::convert::Infallible;
+//!
+//! #[derive(Component)]
+//! pub struct Foreign(f32);
+//! #[derive(Component, Reflect)]
+//! pub struct ForeignMirror(f32);
+//! # impl<'a> From<&'a Foreign> for ForeignMirror {
+//! #   fn from(value: &'a Foreign) -> Self { ForeignMirror(value.0) }
+//! # }
+//! # impl Mirror<Foreign> for ForeignMirror {
+//! #   fn apply(&self, value: &mut Foreign) { value.0 = self.0; }
+//! # }
+//! impl FromMirror<ForeignMirror> for Foreign {
+//!   fn from_mirror(mirror: &ForeignMirror, (): &()) -> Result<Self, Infallible> {
+//!     Ok(Foreign(mirror.0))
+//!   }
+//! }
+//!
+//! assert_mirror_roundtrip::<_, ForeignMirror>(&Foreign(3.0));
+//!
+//! let mut harness = MirrorHarness::new();
+//! harness.add_plugins(MirrorPlugin::<Foreign, ForeignMirror>::new());
+//! let entity = harness.spawn(Foreign(3.0));
+//! harness.step();
+//! harness.get_mut::<ForeignMirror>(entity).0 = 4.0;
+//! harness.step();
+//! assert_eq!(harness.get::<Foreign>(entity).0, 4.0);
+//! ```
+use std::fmt::Write;
+
+use bevy::{app::Plugins, prelude::*, reflect::ReflectRef};
+
+use crate::{FromMirror, MirrorDiff, TryMirror};
+
+/// Compares mirrors with a tolerance on floats.
+///
+/// Values are compared field by field through [`Reflect`], `f32` and `f64`
+/// are equal if they differ by at most the tolerance, other values must
+/// be equal according to [`Reflect::reflect_partial_eq`].
+///
+/// ```rust
+/// use bevy_mod_component_mirror::testing::MirrorRoundtrip;
+/// # use bevy_mod_component_mirror::Mirror;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)] pub struct Foreign(f32);
+/// # #[derive(Component, Reflect)] pub struct ForeignMirror(f32);
+/// # impl<'a> From<&'a Foreign> for ForeignMirror {
+/// #   fn from(value: &'a Foreign) -> Self { ForeignMirror(value.0) }
+/// # }
+/// # impl Mirror<Foreign> for ForeignMirror {
+/// #   fn apply(&self, value: &mut Foreign) { value.0 = self.0; }
+/// # }
+///
+/// let roundtrip = MirrorRoundtrip::new().tolerance(1e-3);
+/// roundtrip.assert::<_, ForeignMirror>(&Foreign(1.0 / 3.0), Foreign(0.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MirrorRoundtrip {
+    tolerance: f64,
+}
+impl MirrorRoundtrip {
+    pub const fn new() -> Self {
+        Self { tolerance: 1e-5 }
+    }
+    /// Set by how much floats may differ.
+    ///
+    /// By default, this is `1e-5`.
+    pub const fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+    /// Apply the mirror of `value` to `target` the way
+    /// [`MirrorPlugin`](crate::MirrorPlugin) does, and check that the mirror
+    /// of `target` is then equal to the mirror of `value`.
+    ///
+    /// Returns `target` after the mirror was applied.
+    ///
+    /// # Panics
+    ///
+    /// If the mirror can't be applied, or the mirrors are different.
+    #[track_caller]
+    pub fn assert<T, U: TryMirror<T> + Reflect>(&self, value: &T, mut target: T) -> T {
+        let mirror = U::from(value);
+        let diff = MirrorDiff::new(&U::from(&target), &mirror);
+        if let Err(error) = mirror.try_apply_diff(&mut target, &diff) {
+            panic!("Couldn't apply {:?}: {error}", &mirror as &dyn Reflect);
+        }
+        self.assert_eq(&U::from(&target), &mirror);
+        target
+    }
+    /// Create `T` from `mirror` with [`FromMirror`], and check that its mirror
+    /// is equal to `mirror`.
+    ///
+    /// Returns the created `T`.
+    ///
+    /// # Panics
+    ///
+    /// If `T` can't be created, or the mirrors are different.
+    #[track_caller]
+    pub fn assert_from_mirror<T: FromMirror<U>, U: TryMirror<T> + Reflect>(&self, mirror: &U) -> T {
+        let value = match T::from_mirror(mirror, &()) {
+            Ok(value) => value,
+            Err(error) => {
+                let mirror: &dyn Reflect = mirror;
+                panic!("Couldn't create source of {mirror:?}: {error}")
+            }
+        };
+        self.assert_eq(&U::from(&value), mirror);
+        value
+    }
+    /// Check that `left` and `right` are equal.
+    ///
+    /// # Panics
+    ///
+    /// If `left` and `right` are different, with the path of the first
+    /// different field.
+    #[track_caller]
+    pub fn assert_eq(&self, left: &dyn Reflect, right: &dyn Reflect) {
+        let mut path = String::new();
+        if !self.reflect_eq(&mut path, left, right) {
+            panic!("Mirrors differ at `{path}`\n  left: {left:?}\n right: {right:?}");
+        }
+    }
+    /// Whether `left` and `right` are equal, `path` is the path of the first
+    /// different field.
+    fn reflect_eq(&self, path: &mut String, left: &dyn Reflect, right: &dyn Reflect) -> bool {
+        let float = |value: &dyn Reflect| match value.as_any() {
+            any if any.is::<f32>() => any.downcast_ref::<f32>().map(|v| f64::from(*v)),
+            any => any.downcast_ref::<f64>().copied(),
+        };
+        if let (Some(left), Some(right)) = (float(left), float(right)) {
+            let both_nan = left.is_nan() && right.is_nan();
+            return both_nan || left == right || (left - right).abs() <= self.tolerance;
+        }
+        let field = |path: &mut String, name: &dyn std::fmt::Display, left, right| {
+            let len = path.len();
+            write!(path, ".{name}").unwrap();
+            let equal = self.reflect_eq(path, left, right);
+            if equal {
+                path.truncate(len);
+            }
+            equal
+        };
+        match (left.reflect_ref(), right.reflect_ref()) {
+            (ReflectRef::Struct(left), ReflectRef::Struct(right)) => {
+                left.field_len() == right.field_len()
+                    && (0..left.field_len()).all(|i| {
+                        let name = left.name_at(i).unwrap_or_default();
+                        match (left.field_at(i), right.field(name)) {
+                            (Some(l), Some(r)) => field(path, &name, l, r),
+                            _ => false,
+                        }
+                    })
+            }
+            (ReflectRef::TupleStruct(left), ReflectRef::TupleStruct(right)) => {
+                left.field_len() == right.field_len()
+                    && (left.iter_fields().zip(right.iter_fields()))
+                        .enumerate()
+                        .all(|(i, (l, r))| field(path, &i, l, r))
+            }
+            (ReflectRef::Tuple(left), ReflectRef::Tuple(right)) => {
+                left.field_len() == right.field_len()
+                    && (left.iter_fields().zip(right.iter_fields()))
+                        .enumerate()
+                        .all(|(i, (l, r))| field(path, &i, l, r))
+            }
+            (ReflectRef::List(left), ReflectRef::List(right)) => {
+                left.len() == right.len()
+                    && (left.iter().zip(right.iter()))
+                        .enumerate()
+                        .all(|(i, (l, r))| field(path, &i, l, r))
+            }
+            (ReflectRef::Array(left), ReflectRef::Array(right)) => {
+                left.len() == right.len()
+                    && (left.iter().zip(right.iter()))
+                        .enumerate()
+                        .all(|(i, (l, r))| field(path, &i, l, r))
+            }
+            (ReflectRef::Enum(left), ReflectRef::Enum(right)) => {
+                left.variant_name() == right.variant_name()
+                    && left.field_len() == right.field_len()
+                    && (left.iter_fields().enumerate()).all(|(i, l)| {
+                        let r = match l.name() {
+                            Some(name) => right.field(name),
+                            None => right.field_at(i),
+                        };
+                        let name = l.name().map_or_else(|| i.to_string(), str::to_owned);
+                        r.is_some_and(|r| field(path, &name, l.value(), r))
+                    })
+            }
+            _ => left.reflect_partial_eq(right) == Some(true),
+        }
+    }
+}
+impl Default for MirrorRoundtrip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Create a new `T` from the mirror `U` of `value` with [`FromMirror`],
+/// and check that the mirror of the new `T` is equal to the mirror of `value`.
+///
+/// Returns the new `T`. Floats may differ by `1e-5`, use [`MirrorRoundtrip`]
+/// to change this, or to check [`TryMirror::try_apply_diff`] on an existing `T`
+/// with [`MirrorRoundtrip::assert`].
+///
+/// # Panics
+///
+/// If `T` can't be created, or the mirrors are different.
+#[track_caller]
+pub fn assert_mirror_roundtrip<T: FromMirror<U>, U: TryMirror<T> + Reflect>(value: &T) -> T {
+    MirrorRoundtrip::new().assert_from_mirror(&U::from(value))
+}
+
+/// A headless [`App`] with [`MinimalPlugins`], to test mirror plugins.
+///
+/// See the [module documentation](self) for an example.
+pub struct MirrorHarness {
+    app: App,
+}
+impl MirrorHarness {
+    pub fn new() -> Self {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        Self { app }
+    }
+    /// Add plugins to the app, such as [`MirrorPlugin`](crate::MirrorPlugin).
+    pub fn add_plugins<M>(&mut self, plugins: impl Plugins<M>) -> &mut Self {
+        self.app.add_plugins(plugins);
+        self
+    }
+    /// Spawn an entity with `bundle`.
+    pub fn spawn(&mut self, bundle: impl Bundle) -> Entity {
+        self.app.world.spawn(bundle).id()
+    }
+    /// Run the app for one frame.
+    pub fn step(&mut self) -> &mut Self {
+        self.app.update();
+        self
+    }
+    /// Run the app for `frames` frames.
+    pub fn steps(&mut self, frames: usize) -> &mut Self {
+        for _ in 0..frames {
+            self.app.update();
+        }
+        self
+    }
+    /// Whether `entity` has a `C` component.
+    pub fn has<C: Component>(&self, entity: Entity) -> bool {
+        self.app.world.get::<C>(entity).is_some()
+    }
+    /// The `C` component of `entity`.
+    ///
+    /// # Panics
+    ///
+    /// If `entity` doesn't have a `C` component.
+    #[track_caller]
+    pub fn get<C: Component>(&self, entity: Entity) -> &C {
+        match self.app.world.get::<C>(entity) {
+            Some(component) => component,
+            None => panic!("{entity:?} has no {}", std::any::type_name::<C>()),
+        }
+    }
+    /// The `C` component of `entity`, to edit it.
+    ///
+    /// # Panics
+    ///
+    /// If `entity` doesn't have a `C` component.
+    #[track_caller]
+    pub fn get_mut<C: Component>(&mut self, entity: Entity) -> Mut<'_, C> {
+        match self.app.world.get_mut::<C>(entity) {
+            Some(component) => component,
+            None => panic!("{entity:?} has no {}", std::any::type_name::<C>()),
+        }
+    }
+    /// All `E` events sent since the last call, such as
+    /// [`MirrorError`](crate::MirrorError).
+    pub fn drain_events<E: Event>(&mut self) -> Vec<E> {
+        match self.app.world.get_resource_mut::<Events<E>>() {
+            Some(mut events) => events.drain().collect(),
+            None => Vec::new(),
+        }
+    }
+    pub fn world(&self) -> &World {
+        &self.app.world
+    }
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.app.world
+    }
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+}
+impl Default for MirrorHarness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,117 @@
+use bevy::prelude::*;
+use bevy_mod_component_mirror::{Mirror, MirrorDiff};
+
+#[derive(Debug, Default, PartialEq)]
+struct Inner {
+    radius: f32,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Foreign {
+    inner: Inner,
+    length: f32,
+    length_writes: u32,
+}
+impl Foreign {
+    fn length(&self) -> f32 {
+        self.length
+    }
+    fn set_length(&mut self, value: f32) {
+        self.length_writes += 1;
+        self.length = value;
+    }
+}
+
+#[derive(Reflect, Mirror, Debug, PartialEq)]
+#[mirror(Foreign)]
+struct ForeignMirror {
+    #[mirror(get = "length", set = "set_length")]
+    length: f32,
+    #[mirror(field = "inner.radius")]
+    radius: f32,
+    // `Foreign` has no `label` field, this only compiles if it's never accessed.
+    #[mirror(skip)]
+    label: String,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Segment {
+    start: f32,
+    end: f32,
+}
+
+#[derive(Reflect, Mirror, Debug, PartialEq)]
+#[mirror(Segment)]
+struct SegmentMirror(#[mirror(field = "start")] f32, #[mirror(field = "end")] f32);
+
+#[test]
+fn named_fields() {
+    let mut foreign = Foreign::default();
+    foreign.set_length(2.0);
+    foreign.inner.radius = 3.0;
+    let mirror = ForeignMirror::from(&foreign);
+    let expected = ForeignMirror {
+        length: 2.0,
+        radius: 3.0,
+        label: String::new(),
+    };
+    assert_eq!(mirror, expected);
+
+    let mirror = ForeignMirror {
+        length: 4.0,
+        radius: 5.0,
+        label: "ignored".to_owned(),
+    };
+    mirror.apply(&mut foreign);
+    assert_eq!(foreign.length, 4.0);
+    assert_eq!(foreign.inner.radius, 5.0);
+    assert_eq!(ForeignMirror::from(&foreign).label, "");
+}
+
+#[test]
+fn apply_diff_writes_changed_fields() {
+    let mut foreign = Foreign::default();
+    let old = ForeignMirror::from(&foreign);
+    let new = ForeignMirror {
+        radius: 3.0,
+        label: "ignored".to_owned(),
+        ..ForeignMirror::from(&foreign)
+    };
+    let diff = MirrorDiff::new(&old, &new);
+    assert!(!diff.is_changed("length"));
+    assert!(diff.is_changed("label"));
+
+    new.apply_diff(&mut foreign, &diff);
+    assert_eq!(foreign.inner.radius, 3.0);
+    assert_eq!(foreign.length_writes, 0);
+
+    new.apply_diff(&mut foreign, &MirrorDiff::all());
+    assert_eq!(foreign.length_writes, 1);
+}
+
+#[test]
+fn tuple_struct() {
+    let mut segment = Segment {
+        start: 1.0,
+        end: 2.0,
+    };
+    let old = SegmentMirror::from(&segment);
+    assert_eq!(old, SegmentMirror(1.0, 2.0));
+
+    let new = SegmentMirror(1.0, 3.0);
+    let diff = MirrorDiff::new(&old, &new);
+    assert!(!diff.is_changed("0"));
+    assert!(diff.is_changed("1"));
+
+    // Only `end` is written, so the change to `start` is kept.
+    segment.start = 5.0;
+    new.apply_diff(&mut segment, &diff);
+    let expected = Segment {
+        start: 5.0,
+        end: 3.0,
+    };
+    assert_eq!(segment, expected);
+
+    new.apply(&mut segment);
+    assert_eq!(segment.start, 1.0);
+}
@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use bevy_mod_component_mirror::testing::MirrorHarness;
+use bevy_mod_component_mirror::{MirrorConflict, MirrorConflictPolicy};
+
+use crate::{harness, Foreign, ForeignMirror, ForeignPlugin};
+
+/// Edit both `Foreign` and its mirror in the same frame.
+fn conflict(policy: MirrorConflictPolicy) -> (MirrorHarness, Entity) {
+    let mut harness = harness(ForeignPlugin::new().conflict_policy(policy));
+    let entity = harness.spawn(Foreign::new(1.0, 2.0));
+    harness.steps(2);
+    harness.get_mut::<Foreign>(entity).a = 3.0;
+    harness.get_mut::<ForeignMirror>(entity).a = 4.0;
+    harness.step();
+    (harness, entity)
+}
+
+#[test]
+fn conflict_source_wins() {
+    let (mut harness, entity) = conflict(MirrorConflictPolicy::SourceWins);
+    assert_eq!(harness.get::<Foreign>(entity).a, 3.0);
+    assert_eq!(harness.get::<ForeignMirror>(entity).a, 3.0);
+    assert!(harness.drain_events::<MirrorConflict>().is_empty());
+}
+
+#[test]
+fn conflict_mirror_wins() {
+    let (mut harness, entity) = conflict(MirrorConflictPolicy::MirrorWins);
+    assert_eq!(harness.get::<Foreign>(entity).a, 4.0);
+    assert_eq!(harness.get::<ForeignMirror>(entity).a, 4.0);
+    assert!(harness.drain_events::<MirrorConflict>().is_empty());
+}
+
+#[test]
+fn conflict_report() {
+    let (mut harness, entity) = conflict(MirrorConflictPolicy::Report);
+    assert_eq!(harness.get::<Foreign>(entity).a, 3.0);
+    assert_eq!(harness.get::<ForeignMirror>(entity).a, 3.0);
+    let conflicts = harness.drain_events::<MirrorConflict>();
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].entity, Some(entity));
+    let lost = conflicts[0].lost.downcast_ref::<ForeignMirror>();
+    assert_eq!(lost, Some(&ForeignMirror { a: 4.0, b: 2.0 }));
+}
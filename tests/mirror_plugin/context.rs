@@ -0,0 +1,44 @@
+use std::convert::Infallible;
+
+use bevy::{ecs::system::SystemParamItem, prelude::*};
+use bevy_mod_component_mirror::{ContextMirror, FromMirror, MirrorDiff, MirrorPlugin};
+
+use crate::{harness, Foreign};
+
+#[derive(Resource)]
+struct Scale(f32);
+
+#[derive(Component, Reflect, Debug, PartialEq)]
+#[reflect(PartialEq)]
+struct ScaledMirror(f32);
+impl ContextMirror<Foreign> for ScaledMirror {
+    type Param = Res<'static, Scale>;
+    type Error = Infallible;
+
+    fn from_source(value: &Foreign, scale: &SystemParamItem<Self::Param>) -> Self {
+        ScaledMirror(value.a * scale.0)
+    }
+    fn apply_to(
+        &self,
+        value: &mut Foreign,
+        _: &MirrorDiff,
+        scale: &SystemParamItem<Self::Param>,
+    ) -> Result<(), Infallible> {
+        value.a = self.0 / scale.0;
+        Ok(())
+    }
+}
+impl FromMirror<ScaledMirror> for Foreign {
+    fn from_mirror(mirror: &ScaledMirror, scale: &Res<Scale>) -> Result<Self, Infallible> {
+        Ok(Foreign::new(mirror.0 / scale.0, 0.0))
+    }
+}
+
+#[test]
+fn create_source_with_param() {
+    let mut harness = harness(MirrorPlugin::<Foreign, ScaledMirror>::new().create_source());
+    harness.world_mut().insert_resource(Scale(10.0));
+    let entity = harness.spawn(ScaledMirror(20.0));
+    harness.step();
+    assert_eq!(harness.get::<Foreign>(entity), &Foreign::new(2.0, 0.0));
+}
@@ -0,0 +1,24 @@
+use bevy_mod_component_mirror::{FromMirror, MirrorError, TryMirror};
+
+use crate::{harness, Foreign, ForeignMirror, ForeignPlugin};
+
+impl FromMirror<ForeignMirror> for Foreign {
+    fn from_mirror(mirror: &ForeignMirror, (): &()) -> Result<Self, &'static str> {
+        let mut value = Foreign::default();
+        mirror.try_apply(&mut value)?;
+        Ok(value)
+    }
+}
+
+#[test]
+fn create_source() {
+    let mut harness = harness(ForeignPlugin::new().create_source());
+    let entity = harness.spawn(ForeignMirror { a: 1.0, b: 2.0 });
+    let invalid = harness.spawn(ForeignMirror { a: -1.0, b: 2.0 });
+    harness.step();
+    assert_eq!(harness.get::<Foreign>(entity), &Foreign::new(1.0, 2.0));
+    assert!(!harness.has::<Foreign>(invalid));
+    let errors = harness.drain_events::<MirrorError>();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].entity, Some(invalid));
+}
@@ -0,0 +1,23 @@
+use crate::{harness, last_changed, Foreign, ForeignMirror, ForeignPlugin};
+
+#[test]
+fn no_echo() {
+    let mut harness = harness(ForeignPlugin::new());
+    let entity = harness.spawn(Foreign::new(1.0, 2.0));
+    harness.steps(2);
+
+    harness.get_mut::<Foreign>(entity).a = 3.0;
+    let source_changed = last_changed::<Foreign>(&harness, entity);
+    harness.steps(2);
+    assert_eq!(harness.get::<ForeignMirror>(entity).a, 3.0);
+    assert_eq!(last_changed::<Foreign>(&harness, entity), source_changed);
+
+    harness.get_mut::<ForeignMirror>(entity).a = 4.0;
+    let mirror_changed = last_changed::<ForeignMirror>(&harness, entity);
+    harness.steps(2);
+    assert_eq!(harness.get::<Foreign>(entity).a, 4.0);
+    assert_eq!(
+        last_changed::<ForeignMirror>(&harness, entity),
+        mirror_changed
+    );
+}
@@ -0,0 +1,22 @@
+use bevy::prelude::DetectChangesMut;
+
+use crate::{harness, last_changed, Foreign, ForeignMirror, ForeignPlugin};
+
+#[test]
+fn unchanged_values_are_not_written() {
+    let mut harness = harness(ForeignPlugin::new());
+    let entity = harness.spawn(Foreign::new(1.0, 2.0));
+    harness.steps(2);
+    let source_changed = last_changed::<Foreign>(&harness, entity);
+    harness.get_mut::<ForeignMirror>(entity).set_changed();
+    harness.step();
+    assert_eq!(last_changed::<Foreign>(&harness, entity), source_changed);
+
+    let mirror_changed = last_changed::<ForeignMirror>(&harness, entity);
+    harness.get_mut::<Foreign>(entity).set_changed();
+    harness.step();
+    assert_eq!(
+        last_changed::<ForeignMirror>(&harness, entity),
+        mirror_changed
+    );
+}
@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+use bevy_mod_component_mirror::{MirrorApplied, MirrorRefreshed};
+
+use crate::{harness, Foreign, ForeignMirror, ForeignPlugin};
+
+#[test]
+fn send_events() {
+    let mut harness = harness(ForeignPlugin::new().send_events());
+    let entity = harness.spawn(Foreign::new(1.0, 2.0));
+    harness.steps(2);
+
+    harness.get_mut::<ForeignMirror>(entity).a = 3.0;
+    harness.step();
+    let applied = harness.drain_events::<MirrorApplied<Foreign>>();
+    assert_eq!(applied.len(), 1);
+    assert_eq!(applied[0].entity, entity);
+    let before = ForeignMirror::from_reflect(&*applied[0].before);
+    assert_eq!(before, Some(ForeignMirror { a: 1.0, b: 2.0 }));
+    assert!(harness
+        .drain_events::<MirrorRefreshed<Foreign>>()
+        .is_empty());
+
+    harness.get_mut::<Foreign>(entity).b = 4.0;
+    harness.step();
+    let refreshed = harness.drain_events::<MirrorRefreshed<Foreign>>();
+    assert_eq!(refreshed.len(), 1);
+    let after = ForeignMirror::from_reflect(&*refreshed[0].after);
+    assert_eq!(after, Some(ForeignMirror { a: 3.0, b: 4.0 }));
+    assert!(harness.drain_events::<MirrorApplied<Foreign>>().is_empty());
+}
@@ -0,0 +1,17 @@
+use bevy_mod_component_mirror::MirrorError;
+
+use crate::{harness, Foreign, ForeignMirror, ForeignPlugin};
+
+#[test]
+fn invalid_mirror_is_reported() {
+    let mut harness = harness(ForeignPlugin::new());
+    let entity = harness.spawn(Foreign::new(1.0, 2.0));
+    harness.step();
+
+    harness.get_mut::<ForeignMirror>(entity).a = -1.0;
+    harness.step();
+    assert_eq!(harness.get::<Foreign>(entity), &Foreign::new(1.0, 2.0));
+    let errors = harness.drain_events::<MirrorError>();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].entity, Some(entity));
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+use bevy_mod_component_mirror::MirrorPlugin;
+
+use crate::{harness, Foreign, ForeignMirror, ForeignPlugin};
+
+#[derive(Component)]
+struct Tunable;
+
+#[test]
+fn filter_late_match() {
+    let mut harness = harness(ForeignPlugin::new().filter::<With<Tunable>>());
+    let tuned = harness.spawn((Foreign::new(1.0, 2.0), Tunable));
+    let entity = harness.spawn(Foreign::new(1.0, 2.0));
+    harness.step();
+    assert!(harness.has::<ForeignMirror>(tuned));
+    assert!(!harness.has::<ForeignMirror>(entity));
+
+    harness.world_mut().entity_mut(entity).insert(Tunable);
+    harness.step();
+    assert!(harness.has::<ForeignMirror>(entity));
+
+    harness.get_mut::<ForeignMirror>(entity).a = 3.0;
+    harness.step();
+    assert_eq!(harness.get::<Foreign>(entity), &Foreign::new(3.0, 2.0));
+}
+
+#[test]
+fn filter_stop_matching() {
+    let mut harness = harness(ForeignPlugin::new().filter::<With<Tunable>>());
+    let entity = harness.spawn((Foreign::new(1.0, 2.0), Tunable));
+    harness.step();
+    assert!(harness.has::<ForeignMirror>(entity));
+
+    harness.world_mut().entity_mut(entity).remove::<Tunable>();
+    harness.step();
+    assert!(!harness.has::<ForeignMirror>(entity));
+
+    harness.get_mut::<Foreign>(entity).a = 5.0;
+    harness.world_mut().entity_mut(entity).insert(Tunable);
+    harness.step();
+    assert_eq!(harness.get::<ForeignMirror>(entity).a, 5.0);
+}
+
+#[test]
+fn filter_type_parameter() {
+    let mut harness = harness(MirrorPlugin::<Foreign, ForeignMirror, With<Tunable>>::new());
+    let entity = harness.spawn(Foreign::new(1.0, 2.0));
+    harness.step();
+    assert!(!harness.has::<ForeignMirror>(entity));
+
+    harness.world_mut().entity_mut(entity).insert(Tunable);
+    harness.step();
+    assert!(harness.has::<ForeignMirror>(entity));
+
+    harness.world_mut().entity_mut(entity).remove::<Tunable>();
+    harness.step();
+    assert!(!harness.has::<ForeignMirror>(entity));
+}
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+use bevy_mod_component_mirror::{
+    MirrorHistory, MirrorHistoryPlugin, MirrorRedo, MirrorSampling, MirrorUndo,
+};
+
+use crate::{harness, Foreign, ForeignMirror, ForeignPlugin};
+
+fn undo_redo(plugin: ForeignPlugin) {
+    let mut harness = harness(plugin);
+    harness.add_plugins(MirrorHistoryPlugin::new().capacity(2));
+    let entity = harness.spawn(Foreign::new(1.0, 0.0));
+    harness.steps(2);
+    for a in [2.0, 3.0, 4.0] {
+        harness.get_mut::<ForeignMirror>(entity).a = a;
+        harness.steps(2);
+    }
+    // Changes to the source are not recorded.
+    harness.get_mut::<Foreign>(entity).b = 5.0;
+    harness.steps(2);
+    harness.get_mut::<Foreign>(entity).b = 0.0;
+    harness.steps(2);
+    let history = harness.world().resource::<MirrorHistory>();
+    assert_eq!(history.undos().count(), 2);
+
+    harness.world_mut().send_event(MirrorUndo);
+    harness.steps(2);
+    assert_eq!(harness.get::<Foreign>(entity), &Foreign::new(3.0, 0.0));
+    assert_eq!(harness.get::<ForeignMirror>(entity).a, 3.0);
+    assert!(harness.world().resource::<MirrorHistory>().can_redo());
+
+    harness.world_mut().send_event(MirrorUndo);
+    harness.world_mut().send_event(MirrorUndo);
+    harness.steps(2);
+    assert_eq!(harness.get::<Foreign>(entity).a, 2.0);
+    assert!(!harness.world().resource::<MirrorHistory>().can_undo());
+
+    harness.world_mut().send_event(MirrorRedo);
+    harness.steps(2);
+    assert_eq!(harness.get::<Foreign>(entity).a, 3.0);
+    assert!(harness.world().resource::<MirrorHistory>().can_redo());
+
+    harness.get_mut::<ForeignMirror>(entity).a = 9.0;
+    harness.step();
+    assert!(!harness.world().resource::<MirrorHistory>().can_redo());
+}
+
+#[test]
+fn undo_redo_every_frame() {
+    undo_redo(ForeignPlugin::new());
+}
+
+#[test]
+fn undo_redo_sampled() {
+    undo_redo(ForeignPlugin::new().sampling(MirrorSampling::Frames(100)));
+}
+
+#[test]
+fn continuous_edit_is_one_undo() {
+    let mut harness = harness(ForeignPlugin::new());
+    harness.add_plugins(MirrorHistoryPlugin::new());
+    let entity = harness.spawn(Foreign::new(1.0, 0.0));
+    let other = harness.spawn(Foreign::new(1.0, 0.0));
+    harness.steps(2);
+    // Dragging a slider over several frames, while editing another entity.
+    for a in [2.0, 3.0, 4.0] {
+        harness.get_mut::<ForeignMirror>(entity).a = a;
+        harness.get_mut::<ForeignMirror>(other).b = a;
+        harness.step();
+    }
+    harness.step();
+    harness.get_mut::<ForeignMirror>(entity).a = 5.0;
+    harness.step();
+
+    let history = harness.world().resource::<MirrorHistory>();
+    let edits: Vec<_> = history.undos().collect();
+    assert_eq!(edits.len(), 3);
+    let value = |edit: &dyn Reflect| ForeignMirror::from_reflect(edit).unwrap();
+    assert_eq!(edits[1].entity, Some(other));
+    assert_eq!(value(&*edits[1].after), ForeignMirror { a: 1.0, b: 4.0 });
+    assert_eq!(edits[2].entity, Some(entity));
+    assert_eq!(value(&*edits[2].before), ForeignMirror { a: 1.0, b: 0.0 });
+    assert_eq!(value(&*edits[2].after), ForeignMirror { a: 4.0, b: 0.0 });
+    assert_eq!(value(&*edits[0].before), ForeignMirror { a: 4.0, b: 0.0 });
+
+    for _ in 0..3 {
+        harness.world_mut().send_event(MirrorUndo);
+    }
+    harness.step();
+    assert_eq!(harness.get::<Foreign>(entity).a, 1.0);
+    assert_eq!(harness.get::<Foreign>(other).b, 0.0);
+}
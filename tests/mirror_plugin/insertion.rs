@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+use crate::{harness, Foreign, ForeignMirror, ForeignPlugin};
+
+#[derive(Resource, Default)]
+struct MirroredInPostUpdate(bool);
+
+#[test]
+fn insert_same_frame() {
+    let mut harness = harness(ForeignPlugin::new().insert_same_frame());
+    let spawn = |mut cmds: Commands| {
+        cmds.spawn(Foreign::new(1.0, 2.0));
+    };
+    let check = |mirrors: Query<&ForeignMirror>, mut seen: ResMut<MirroredInPostUpdate>| {
+        seen.0 = !mirrors.is_empty();
+    };
+    harness
+        .app_mut()
+        .init_resource::<MirroredInPostUpdate>()
+        .add_systems(Startup, spawn)
+        .add_systems(PostUpdate, check);
+    harness.step();
+    assert!(harness.world().resource::<MirroredInPostUpdate>().0);
+}
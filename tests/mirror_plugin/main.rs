@@ -0,0 +1,141 @@
+use bevy::{app::Plugins, prelude::*};
+use bevy_mod_component_mirror::testing::MirrorHarness;
+use bevy_mod_component_mirror::{
+    MirrorApplied, MirrorDiff, MirrorError, MirrorHistory, MirrorHistoryPlugin, MirrorPlugin,
+    MirrorRefreshed, TryMirror,
+};
+
+mod conflict;
+mod context;
+mod create_source;
+mod echo;
+mod equality;
+mod events;
+mod fallible;
+mod filter;
+mod history;
+mod insertion;
+mod markers;
+mod modes;
+mod registry;
+mod removal;
+mod resource;
+mod sampling;
+mod settings;
+
+#[derive(Component, Debug, Default, PartialEq)]
+struct Foreign {
+    a: f32,
+    b: f32,
+}
+impl Foreign {
+    const fn new(a: f32, b: f32) -> Self {
+        Self { a, b }
+    }
+}
+
+#[derive(Component, Reflect, Debug, Default, PartialEq)]
+#[reflect(PartialEq)]
+struct ForeignMirror {
+    a: f32,
+    b: f32,
+}
+impl<'a> From<&'a Foreign> for ForeignMirror {
+    fn from(value: &'a Foreign) -> Self {
+        Self {
+            a: value.a,
+            b: value.b,
+        }
+    }
+}
+impl TryMirror<Foreign> for ForeignMirror {
+    type Error = &'static str;
+
+    fn try_apply(&self, value: &mut Foreign) -> Result<(), Self::Error> {
+        self.try_apply_diff(value, &MirrorDiff::all())
+    }
+    fn try_apply_diff(&self, value: &mut Foreign, diff: &MirrorDiff) -> Result<(), Self::Error> {
+        if self.a < 0.0 {
+            return Err("`a` must be positive");
+        }
+        if diff.is_changed("a") {
+            value.a = self.a;
+        }
+        if diff.is_changed("b") {
+            value.b = self.b;
+        }
+        Ok(())
+    }
+}
+
+type ForeignPlugin = MirrorPlugin<Foreign, ForeignMirror>;
+
+fn harness<M>(plugin: impl Plugins<M>) -> MirrorHarness {
+    let mut harness = MirrorHarness::new();
+    harness.add_plugins(plugin);
+    harness
+}
+
+fn last_changed<C: Component>(harness: &MirrorHarness, entity: Entity) -> u32 {
+    let ticks = harness.world().entity(entity).get_change_ticks::<C>();
+    ticks.unwrap().last_changed_tick().get()
+}
+
+#[test]
+fn sync_both_ways() {
+    let mut harness = harness(ForeignPlugin::new());
+    let entity = harness.spawn(Foreign::new(1.0, 2.0));
+    harness.step();
+    let expected = ForeignMirror { a: 1.0, b: 2.0 };
+    assert_eq!(harness.get::<ForeignMirror>(entity), &expected);
+
+    harness.get_mut::<Foreign>(entity).a = 3.0;
+    harness.step();
+    assert_eq!(harness.get::<ForeignMirror>(entity).a, 3.0);
+
+    harness.get_mut::<ForeignMirror>(entity).b = 4.0;
+    harness.step();
+    assert_eq!(harness.get::<Foreign>(entity), &Foreign::new(3.0, 4.0));
+}
+
+#[test]
+fn many_entities() {
+    let mut harness = harness(ForeignPlugin::new().send_events());
+    harness.add_plugins(MirrorHistoryPlugin::new().capacity(10_000));
+    let entities: Vec<_> = (0..10_000)
+        .map(|i| harness.spawn(Foreign::new(i as f32, 0.0)))
+        .collect();
+    harness.steps(2);
+    for (i, &entity) in entities.iter().enumerate() {
+        match i % 4 {
+            0 => harness.get_mut::<Foreign>(entity).b = 1.0,
+            1 => harness.get_mut::<ForeignMirror>(entity).b = 2.0,
+            2 => harness.get_mut::<ForeignMirror>(entity).a = -1.0,
+            _ => {}
+        }
+    }
+    harness.step();
+    for (i, &entity) in entities.iter().enumerate() {
+        let b = [1.0, 2.0, 0.0, 0.0][i % 4];
+        let expected = Foreign::new(i as f32, b);
+        assert_eq!(harness.get::<Foreign>(entity), &expected);
+        if i % 4 != 2 {
+            assert_eq!(harness.get::<ForeignMirror>(entity).b, b);
+        }
+    }
+    // Events and edits are in entity order, whichever thread found them.
+    let in_order = |found: Vec<Entity>, rest: usize| {
+        let expected: Vec<_> = entities.iter().skip(rest).step_by(4).copied().collect();
+        assert_eq!(found, expected);
+    };
+    let refreshed = harness.drain_events::<MirrorRefreshed<Foreign>>();
+    in_order(refreshed.iter().map(|e| e.entity).collect(), 0);
+    let applied = harness.drain_events::<MirrorApplied<Foreign>>();
+    in_order(applied.iter().map(|e| e.entity).collect(), 1);
+    let errors = harness.drain_events::<MirrorError>();
+    in_order(errors.iter().filter_map(|e| e.entity).collect(), 2);
+    let history = harness.world().resource::<MirrorHistory>();
+    let mut edits: Vec<_> = history.undos().filter_map(|e| e.entity).collect();
+    edits.reverse();
+    in_order(edits, 1);
+}
@@ -0,0 +1,50 @@
+use bevy_mod_component_mirror::{MirrorPaused, MirrorRemovalPolicy, NoMirror};
+
+use crate::{harness, Foreign, ForeignMirror, ForeignPlugin};
+
+#[test]
+fn no_mirror() {
+    let plugin = ForeignPlugin::new().removal_policy(MirrorRemovalPolicy::Readd);
+    let mut harness = harness(plugin);
+    let entity = harness.spawn((Foreign::new(1.0, 2.0), NoMirror::<Foreign>::new()));
+    harness.steps(2);
+    assert!(!harness.has::<ForeignMirror>(entity));
+
+    harness
+        .world_mut()
+        .entity_mut(entity)
+        .remove::<NoMirror<Foreign>>();
+    harness.step();
+    assert!(harness.has::<ForeignMirror>(entity));
+
+    harness
+        .world_mut()
+        .entity_mut(entity)
+        .insert(NoMirror::<Foreign>::new());
+    harness.steps(2);
+    assert!(!harness.has::<ForeignMirror>(entity));
+}
+
+#[test]
+fn paused_mirror_resumes_from_source() {
+    let mut harness = harness(ForeignPlugin::new());
+    let entity = harness.spawn(Foreign::new(1.0, 2.0));
+    harness.step();
+    let paused = MirrorPaused::<Foreign>::new();
+    harness.world_mut().entity_mut(entity).insert(paused);
+
+    harness.get_mut::<ForeignMirror>(entity).b = 5.0;
+    harness.step();
+    assert_eq!(harness.get::<Foreign>(entity).b, 2.0);
+
+    harness.get_mut::<Foreign>(entity).a = 3.0;
+    harness.step();
+    assert_eq!(harness.get::<ForeignMirror>(entity).a, 1.0);
+
+    let mut entity_mut = harness.world_mut().entity_mut(entity);
+    entity_mut.remove::<MirrorPaused<Foreign>>();
+    harness.step();
+    let expected = ForeignMirror { a: 3.0, b: 2.0 };
+    assert_eq!(harness.get::<ForeignMirror>(entity), &expected);
+    assert_eq!(harness.get::<Foreign>(entity), &Foreign::new(3.0, 2.0));
+}
@@ -0,0 +1,26 @@
+use bevy_mod_component_mirror::MirrorMode;
+
+use crate::{harness, Foreign, ForeignMirror, ForeignPlugin};
+
+#[test]
+fn one_way_modes() {
+    let mut to_mirror = harness(ForeignPlugin::new().mode(MirrorMode::SourceToMirror));
+    let entity = to_mirror.spawn(Foreign::new(1.0, 2.0));
+    to_mirror.step();
+    to_mirror.get_mut::<ForeignMirror>(entity).a = 3.0;
+    to_mirror.step();
+    assert_eq!(to_mirror.get::<Foreign>(entity).a, 1.0);
+    to_mirror.get_mut::<Foreign>(entity).a = 4.0;
+    to_mirror.step();
+    assert_eq!(to_mirror.get::<ForeignMirror>(entity).a, 4.0);
+
+    let mut to_source = harness(ForeignPlugin::new().mode(MirrorMode::MirrorToSource));
+    let entity = to_source.spawn(Foreign::new(1.0, 2.0));
+    to_source.step();
+    to_source.get_mut::<Foreign>(entity).a = 3.0;
+    to_source.step();
+    assert_eq!(to_source.get::<ForeignMirror>(entity).a, 1.0);
+    to_source.get_mut::<ForeignMirror>(entity).b = 4.0;
+    to_source.step();
+    assert_eq!(to_source.get::<Foreign>(entity).b, 4.0);
+}
@@ -0,0 +1,56 @@
+use std::any::{type_name, TypeId};
+
+use bevy::prelude::*;
+use bevy_mod_component_mirror::{Mirror, MirrorMode, MirrorPlugin, MirrorRegistry, ReflectMirror};
+
+use crate::{harness, Foreign, ForeignMirror, ForeignPlugin};
+
+#[derive(Component, Reflect, Debug, PartialEq)]
+struct LengthMirror(f32);
+impl<'a> From<&'a Foreign> for LengthMirror {
+    fn from(value: &'a Foreign) -> Self {
+        Self(value.a.hypot(value.b))
+    }
+}
+impl Mirror<Foreign> for LengthMirror {
+    fn apply(&self, _: &mut Foreign) {}
+}
+
+#[test]
+fn registered_mirrors() {
+    let length = MirrorPlugin::<Foreign, LengthMirror>::new().mode(MirrorMode::SourceToMirror);
+    let harness = harness((ForeignPlugin::new(), length));
+    let foreign_mirror = ReflectMirror {
+        source: TypeId::of::<Foreign>(),
+        source_name: type_name::<Foreign>(),
+        mirror: TypeId::of::<ForeignMirror>(),
+        mirror_name: type_name::<ForeignMirror>(),
+        mode: MirrorMode::Bidirectional,
+    };
+    let length_mirror = ReflectMirror {
+        mirror: TypeId::of::<LengthMirror>(),
+        mirror_name: type_name::<LengthMirror>(),
+        mode: MirrorMode::SourceToMirror,
+        ..foreign_mirror
+    };
+
+    let registry = harness.world().resource::<AppTypeRegistry>().read();
+    let data = |mirror| registry.get_type_data::<ReflectMirror>(mirror);
+    assert_eq!(data(TypeId::of::<ForeignMirror>()), Some(&foreign_mirror));
+    assert_eq!(data(TypeId::of::<LengthMirror>()), Some(&length_mirror));
+
+    let mirrors = harness.world().resource::<MirrorRegistry>();
+    let get = |mirror| mirrors.get(mirror);
+    assert_eq!(get(TypeId::of::<ForeignMirror>()), Some(&foreign_mirror));
+    assert_eq!(get(TypeId::of::<LengthMirror>()), Some(&length_mirror));
+    assert_eq!(get(TypeId::of::<Foreign>()), None);
+
+    let mut mirrors_of: Vec<_> = mirrors.mirrors_of(TypeId::of::<Foreign>()).collect();
+    mirrors_of.sort_by_key(|data| data.mirror_name);
+    let mut expected = vec![&foreign_mirror, &length_mirror];
+    expected.sort_by_key(|data| data.mirror_name);
+    assert_eq!(mirrors_of, expected);
+
+    assert!(mirrors.is_mirrored(TypeId::of::<Foreign>()));
+    assert!(!mirrors.is_mirrored(TypeId::of::<ForeignMirror>()));
+}
@@ -0,0 +1,41 @@
+use bevy_mod_component_mirror::MirrorRemovalPolicy;
+
+use crate::{harness, Foreign, ForeignMirror, ForeignPlugin};
+
+#[test]
+fn source_removal_removes_mirror() {
+    let mut harness = harness(ForeignPlugin::new());
+    let entity = harness.spawn(Foreign::new(1.0, 2.0));
+    harness.step();
+    assert!(harness.has::<ForeignMirror>(entity));
+
+    harness.world_mut().entity_mut(entity).remove::<Foreign>();
+    harness.step();
+    assert!(!harness.has::<ForeignMirror>(entity));
+}
+
+#[test]
+fn removal_policies() {
+    let removed = |policy| {
+        let mut harness = harness(ForeignPlugin::new().removal_policy(policy));
+        let entity = harness.spawn(Foreign::new(1.0, 2.0));
+        harness.step();
+        harness.get_mut::<Foreign>(entity).a = 3.0;
+        harness
+            .world_mut()
+            .entity_mut(entity)
+            .remove::<ForeignMirror>();
+        harness.steps(2);
+        (harness, entity)
+    };
+    let (harness, entity) = removed(MirrorRemovalPolicy::Ignore);
+    assert!(harness.has::<Foreign>(entity));
+    assert!(!harness.has::<ForeignMirror>(entity));
+
+    let (harness, entity) = removed(MirrorRemovalPolicy::Readd);
+    assert_eq!(harness.get::<ForeignMirror>(entity).a, 3.0);
+
+    let (harness, entity) = removed(MirrorRemovalPolicy::RemoveSource);
+    assert!(!harness.has::<Foreign>(entity));
+    assert!(!harness.has::<ForeignMirror>(entity));
+}
@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+use bevy_mod_component_mirror::testing::MirrorHarness;
+use bevy_mod_component_mirror::{Mirror, MirrorResourcePlugin};
+
+#[derive(Resource)]
+struct Gravity(f32);
+
+#[derive(Resource, Reflect, Debug, PartialEq)]
+#[reflect(PartialEq)]
+struct GravityMirror(f32);
+impl<'a> From<&'a Gravity> for GravityMirror {
+    fn from(value: &'a Gravity) -> Self {
+        GravityMirror(value.0)
+    }
+}
+impl Mirror<Gravity> for GravityMirror {
+    fn apply(&self, value: &mut Gravity) {
+        value.0 = self.0;
+    }
+}
+
+#[test]
+fn sync_resource() {
+    let mut harness = MirrorHarness::new();
+    harness.add_plugins(MirrorResourcePlugin::<Gravity, GravityMirror>::new());
+    harness.step();
+    assert!(harness.world().get_resource::<GravityMirror>().is_none());
+
+    harness.world_mut().insert_resource(Gravity(9.8));
+    harness.step();
+    assert_eq!(harness.world().resource::<GravityMirror>().0, 9.8);
+
+    harness.world_mut().resource_mut::<GravityMirror>().0 = 1.6;
+    harness.step();
+    assert_eq!(harness.world().resource::<Gravity>().0, 1.6);
+
+    harness.world_mut().resource_mut::<Gravity>().0 = 3.7;
+    harness.steps(2);
+    assert_eq!(harness.world().resource::<GravityMirror>().0, 3.7);
+    assert_eq!(harness.world().resource::<Gravity>().0, 3.7);
+
+    harness.world_mut().remove_resource::<Gravity>();
+    harness.step();
+    assert!(harness.world().get_resource::<GravityMirror>().is_none());
+}
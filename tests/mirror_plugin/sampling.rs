@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use bevy::time::TimeUpdateStrategy;
+use bevy_mod_component_mirror::MirrorSampling;
+
+use crate::{harness, Foreign, ForeignMirror, ForeignPlugin};
+
+#[test]
+fn sampling_keeps_unsampled_source_changes() {
+    let mut harness = harness(ForeignPlugin::new().sampling(MirrorSampling::Frames(10)));
+    let entity = harness.spawn(Foreign::new(0.0, 0.0));
+    harness.steps(2);
+
+    harness.get_mut::<Foreign>(entity).a = 4.0;
+    harness.step();
+    assert_eq!(harness.get::<ForeignMirror>(entity).a, 0.0);
+
+    harness.get_mut::<ForeignMirror>(entity).b = 5.0;
+    harness.step();
+    assert_eq!(harness.get::<Foreign>(entity), &Foreign::new(4.0, 5.0));
+
+    harness.steps(10);
+    let expected = ForeignMirror { a: 4.0, b: 5.0 };
+    assert_eq!(harness.get::<ForeignMirror>(entity), &expected);
+}
+
+#[test]
+fn sampling_frames() {
+    let mut harness = harness(ForeignPlugin::new().sampling(MirrorSampling::Frames(3)));
+    let entity = harness.spawn(Foreign::new(0.0, 0.0));
+    harness.step();
+
+    let mut updates = 0;
+    let mut last = 0.0;
+    for i in 1..=9 {
+        harness.get_mut::<Foreign>(entity).a = i as f32;
+        harness.step();
+        let mirrored = harness.get::<ForeignMirror>(entity).a;
+        if mirrored != last {
+            updates += 1;
+            last = mirrored;
+        }
+    }
+    assert_eq!(updates, 3);
+}
+
+#[test]
+fn sampling_interval() {
+    let sampling = MirrorSampling::Interval(Duration::from_millis(250));
+    let mut harness = harness(ForeignPlugin::new().sampling(sampling));
+    let frame = TimeUpdateStrategy::ManualDuration(Duration::from_millis(100));
+    harness.world_mut().insert_resource(frame);
+    let entity = harness.spawn(Foreign::new(0.0, 0.0));
+    harness.step();
+
+    let mut updates = 0;
+    let mut last = 0.0;
+    for i in 1..=9 {
+        harness.get_mut::<Foreign>(entity).a = i as f32;
+        harness.step();
+        let mirrored = harness.get::<ForeignMirror>(entity).a;
+        if mirrored != last {
+            updates += 1;
+            last = mirrored;
+        }
+    }
+    assert_eq!(updates, 3);
+    // Edits to the mirror are not throttled.
+    harness.get_mut::<ForeignMirror>(entity).b = 1.0;
+    harness.step();
+    assert_eq!(harness.get::<Foreign>(entity).b, 1.0);
+}
@@ -0,0 +1,36 @@
+use bevy_mod_component_mirror::{MirrorRemovalPolicy, MirrorSettings};
+
+use crate::{harness, Foreign, ForeignMirror, ForeignPlugin};
+
+#[test]
+fn settings_toggle() {
+    let plugin = ForeignPlugin::new().removal_policy(MirrorRemovalPolicy::RemoveSource);
+    let mut harness = harness(plugin);
+    let entity = harness.spawn(Foreign::new(1.0, 2.0));
+    harness.step();
+
+    let mut settings = harness.world_mut().resource_mut::<MirrorSettings>();
+    settings.set_enabled_for::<Foreign>(false);
+    harness.step();
+    assert!(!harness.has::<ForeignMirror>(entity));
+    // Turning mirrors off doesn't count as removing them.
+    assert!(harness.has::<Foreign>(entity));
+
+    harness.get_mut::<Foreign>(entity).a = 3.0;
+    let mut settings = harness.world_mut().resource_mut::<MirrorSettings>();
+    settings.set_enabled_for::<Foreign>(true);
+    harness.steps(2);
+    assert!(harness.has::<Foreign>(entity));
+    assert_eq!(harness.get::<ForeignMirror>(entity).a, 3.0);
+
+    let mut settings = harness.world_mut().resource_mut::<MirrorSettings>();
+    settings.set_enabled(false);
+    harness.step();
+    harness
+        .world_mut()
+        .resource_mut::<MirrorSettings>()
+        .set_enabled(true);
+    harness.steps(2);
+    assert!(harness.has::<Foreign>(entity));
+    assert!(harness.has::<ForeignMirror>(entity));
+}
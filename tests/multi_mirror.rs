@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+use bevy_mod_component_mirror::testing::MirrorHarness;
+use bevy_mod_component_mirror::{MirrorDiff, MirrorError, MultiMirror, MultiMirrorPlugin};
+
+#[derive(Component)]
+struct Friction(f32);
+#[derive(Component)]
+struct Restitution(f32);
+
+#[derive(Component, Reflect, Debug, PartialEq)]
+#[reflect(PartialEq)]
+struct SurfaceMirror {
+    friction: f32,
+    restitution: f32,
+}
+impl<'a> From<(&'a Friction, &'a Restitution)> for SurfaceMirror {
+    fn from((friction, restitution): (&'a Friction, &'a Restitution)) -> Self {
+        SurfaceMirror {
+            friction: friction.0,
+            restitution: restitution.0,
+        }
+    }
+}
+impl MultiMirror<(Friction, Restitution)> for SurfaceMirror {
+    type Error = &'static str;
+
+    fn apply(
+        &self,
+        (mut friction, mut restitution): (Mut<Friction>, Mut<Restitution>),
+        diff: &MirrorDiff,
+    ) -> Result<(), Self::Error> {
+        if self.friction < 0.0 {
+            return Err("friction must be positive");
+        }
+        if diff.is_changed("friction") {
+            friction.0 = self.friction;
+        }
+        if diff.is_changed("restitution") {
+            restitution.0 = self.restitution;
+        }
+        Ok(())
+    }
+}
+
+fn harness() -> MirrorHarness {
+    let mut harness = MirrorHarness::new();
+    harness.add_plugins(MultiMirrorPlugin::<(Friction, Restitution), SurfaceMirror>::new());
+    harness
+}
+
+fn last_changed<C: Component>(harness: &MirrorHarness, entity: Entity) -> u32 {
+    let ticks = harness.world().entity(entity).get_change_ticks::<C>();
+    ticks.unwrap().last_changed_tick().get()
+}
+
+#[test]
+fn added_with_all_sources() {
+    let mut harness = harness();
+    let entity = harness.spawn(Friction(0.5));
+    harness.step();
+    assert!(!harness.has::<SurfaceMirror>(entity));
+
+    harness
+        .world_mut()
+        .entity_mut(entity)
+        .insert(Restitution(0.2));
+    harness.step();
+    let expected = SurfaceMirror {
+        friction: 0.5,
+        restitution: 0.2,
+    };
+    assert_eq!(harness.get::<SurfaceMirror>(entity), &expected);
+
+    harness.world_mut().entity_mut(entity).remove::<Friction>();
+    harness.step();
+    assert!(!harness.has::<SurfaceMirror>(entity));
+}
+
+#[test]
+fn sync_both_ways() {
+    let mut harness = harness();
+    let entity = harness.spawn((Friction(0.5), Restitution(0.2)));
+    harness.step();
+
+    harness.get_mut::<Restitution>(entity).0 = 0.7;
+    harness.step();
+    assert_eq!(harness.get::<SurfaceMirror>(entity).restitution, 0.7);
+
+    harness.get_mut::<SurfaceMirror>(entity).friction = 0.9;
+    harness.step();
+    assert_eq!(harness.get::<Friction>(entity).0, 0.9);
+}
+
+#[test]
+fn only_write_changed_sources() {
+    let mut harness = harness();
+    let entity = harness.spawn((Friction(0.5), Restitution(0.2)));
+    harness.step();
+    let restitution_changed = last_changed::<Restitution>(&harness, entity);
+
+    harness.get_mut::<SurfaceMirror>(entity).friction = 0.9;
+    harness.step();
+    assert_eq!(harness.get::<Friction>(entity).0, 0.9);
+    assert_eq!(
+        last_changed::<Restitution>(&harness, entity),
+        restitution_changed
+    );
+}
+
+#[test]
+fn invalid_mirror_is_reported() {
+    let mut harness = harness();
+    let entity = harness.spawn((Friction(0.5), Restitution(0.2)));
+    harness.step();
+
+    harness.get_mut::<SurfaceMirror>(entity).friction = -1.0;
+    harness.step();
+    assert_eq!(harness.get::<Friction>(entity).0, 0.5);
+    let errors = harness.drain_events::<MirrorError>();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].entity, Some(entity));
+}
@@ -0,0 +1,266 @@
+use std::any::TypeId;
+
+use bevy::{ecs::reflect::ReflectMapEntities, prelude::*, utils::HashMap};
+use bevy_mod_component_mirror::rapier_mirrors::{
+    AdditionalMassPropertiesMirror, ColliderMassPropertiesMirror, ColliderMirror,
+    ColliderMirrorPlugin, ImpulseJointMirror, ImpulseJointMirrorPlugin, InvalidShape,
+};
+use bevy_mod_component_mirror::testing::{assert_mirror_roundtrip, MirrorHarness, MirrorRoundtrip};
+use bevy_mod_component_mirror::{FromMirror, MirrorError, RapierMirrorsPlugins, TryMirror};
+use bevy_rapier3d::prelude::*;
+
+fn colliders() -> Vec<Collider> {
+    vec![
+        Collider::ball(0.5),
+        Collider::cuboid(1.0, 2.0, 3.0),
+        Collider::round_cuboid(1.0, 2.0, 3.0, 0.1),
+        Collider::capsule(Vec3::ZERO, Vec3::Y, 0.3),
+        Collider::segment(Vec3::X, Vec3::NEG_Z),
+        Collider::triangle(Vec3::X, Vec3::Y, Vec3::Z),
+        Collider::round_triangle(Vec3::X, Vec3::Y, Vec3::Z, 0.2),
+        Collider::cylinder(1.5, 0.5),
+        Collider::round_cylinder(1.5, 0.5, 0.1),
+        Collider::cone(1.5, 0.5),
+        Collider::round_cone(1.5, 0.5, 0.1),
+        Collider::compound(vec![
+            (Vec3::X, Quat::from_rotation_y(0.3), Collider::ball(0.5)),
+            (Vec3::NEG_X, Quat::IDENTITY, Collider::cuboid(0.1, 0.2, 0.3)),
+        ]),
+        Collider::halfspace(Vec3::Y).unwrap(),
+    ]
+}
+
+fn mass_properties() -> MassProperties {
+    MassProperties {
+        local_center_of_mass: Vec3::new(0.1, 0.2, 0.3),
+        mass: 4.0,
+        principal_inertia_local_frame: Quat::from_rotation_x(0.5),
+        principal_inertia: Vec3::new(1.0, 2.0, 3.0),
+    }
+}
+
+fn joints(parent: Entity) -> Vec<ImpulseJoint> {
+    let revolute = RevoluteJointBuilder::new(Vec3::Y)
+        .local_anchor1(Vec3::new(0.0, 1.0, 0.0))
+        .local_anchor2(Vec3::new(0.0, -1.0, 0.5))
+        .limits([-1.0, 1.0])
+        .motor_position(0.5, 10.0, 2.0);
+    let prismatic = PrismaticJointBuilder::new(Vec3::X)
+        .local_anchor1(Vec3::Z)
+        .limits([-0.5, 2.0])
+        .motor_velocity(1.0, 0.5)
+        .motor_model(MotorModel::AccelerationBased);
+    let spherical = SphericalJointBuilder::new()
+        .local_anchor2(Vec3::ONE)
+        .motor_position(JointAxis::AngY, 0.2, 3.0, 1.0);
+    let fixed = FixedJointBuilder::new()
+        .local_basis1(Quat::from_rotation_z(1.0))
+        .local_anchor2(Vec3::NEG_Y);
+    let mut generic = GenericJointBuilder::new(JointAxesMask::LIN_AXES)
+        .limits(JointAxis::AngX, [-0.3, 0.3])
+        .local_basis2(Quat::from_rotation_x(0.7))
+        .build();
+    generic.set_contacts_enabled(false);
+    let mut motorized = GenericJointBuilder::new(JointAxesMask::Y)
+        .limits(JointAxis::X, [-1.0, 1.0])
+        .limits(JointAxis::AngZ, [-0.2, 0.4])
+        .build();
+    for (i, axis) in [JointAxis::X, JointAxis::Y, JointAxis::Z]
+        .into_iter()
+        .enumerate()
+    {
+        motorized.set_motor(axis, i as f32, 1.0, 2.0, 0.5);
+        motorized.set_motor_model(axis, MotorModel::AccelerationBased);
+    }
+    for axis in [JointAxis::AngX, JointAxis::AngY, JointAxis::AngZ] {
+        motorized.set_motor_velocity(axis, -1.0, 0.3);
+    }
+    vec![
+        ImpulseJoint::new(parent, revolute),
+        ImpulseJoint::new(parent, prismatic),
+        ImpulseJoint::new(parent, spherical),
+        ImpulseJoint::new(parent, fixed),
+        ImpulseJoint::new(parent, generic),
+        ImpulseJoint::new(parent, motorized),
+    ]
+}
+
+/// Check that `right` has the same axes, limits and motors as `left`.
+#[track_caller]
+fn assert_same_joint(left: &GenericJoint, right: &GenericJoint) {
+    assert_eq!(left.locked_axes(), right.locked_axes());
+    assert_eq!(left.contacts_enabled(), right.contacts_enabled());
+    for axis in [
+        JointAxis::X,
+        JointAxis::Y,
+        JointAxis::Z,
+        JointAxis::AngX,
+        JointAxis::AngY,
+        JointAxis::AngZ,
+    ] {
+        assert_eq!(left.limits(axis), right.limits(axis), "{axis:?} limits");
+        assert_eq!(left.motor(axis), right.motor(axis), "{axis:?} motor");
+    }
+}
+
+#[test]
+fn collider_roundtrip() {
+    for collider in colliders() {
+        assert_mirror_roundtrip::<_, ColliderMirror>(&collider);
+        MirrorRoundtrip::new().assert::<_, ColliderMirror>(&collider, Collider::ball(2.0));
+    }
+}
+
+#[test]
+fn mass_properties_roundtrip() {
+    let collider_mass = [
+        ColliderMassProperties::Density(2.0),
+        ColliderMassProperties::Mass(3.0),
+        ColliderMassProperties::MassProperties(mass_properties()),
+    ];
+    for mass in collider_mass {
+        assert_mirror_roundtrip::<_, ColliderMassPropertiesMirror>(&mass);
+        let target = ColliderMassProperties::default();
+        MirrorRoundtrip::new().assert::<_, ColliderMassPropertiesMirror>(&mass, target);
+    }
+    let additional_mass = [
+        AdditionalMassProperties::Mass(3.0),
+        AdditionalMassProperties::MassProperties(mass_properties()),
+    ];
+    for mass in additional_mass {
+        assert_mirror_roundtrip::<_, AdditionalMassPropertiesMirror>(&mass);
+        let target = AdditionalMassProperties::default();
+        MirrorRoundtrip::new().assert::<_, AdditionalMassPropertiesMirror>(&mass, target);
+    }
+}
+
+#[test]
+fn impulse_joint_roundtrip() {
+    let mut world = World::new();
+    let parent = world.spawn_empty().id();
+    let other_parent = world.spawn_empty().id();
+    for joint in joints(parent) {
+        let created = assert_mirror_roundtrip::<_, ImpulseJointMirror>(&joint);
+        assert_same_joint(&joint.data, &created.data);
+
+        let target = ImpulseJoint::new(other_parent, FixedJointBuilder::new());
+        let applied = MirrorRoundtrip::new().assert::<_, ImpulseJointMirror>(&joint, target);
+        assert_same_joint(&joint.data, &applied.data);
+
+        // As loaded from a scene.
+        let dynamic = ImpulseJointMirror::from(&joint).clone_value();
+        let mirror = ImpulseJointMirror::from_reflect(&*dynamic).unwrap();
+        let loaded = MirrorRoundtrip::new().assert_from_mirror::<ImpulseJoint, _>(&mirror);
+        assert_same_joint(&joint.data, &loaded.data);
+    }
+}
+
+#[test]
+fn joint_from_mirror() {
+    let mut harness = MirrorHarness::new();
+    harness.add_plugins(
+        RapierMirrorsPlugins
+            .build()
+            .set(ImpulseJointMirrorPlugin::new().create_source()),
+    );
+    let parent = harness.spawn(());
+    let revolute = joints(parent).remove(0);
+    let mirror = ImpulseJointMirror::from(&revolute);
+    let entity = harness.spawn(ImpulseJointMirror::from_reflect(&*mirror.clone_value()).unwrap());
+    harness.step();
+    let created = harness.get::<ImpulseJoint>(entity);
+    assert_eq!(created.parent, parent);
+    assert_same_joint(&revolute.data, &created.data);
+
+    let mut motorized = joints(parent).remove(5);
+    motorized.data.set_motor_max_force(JointAxis::Y, 4.0);
+    let mirror = ImpulseJointMirror::from(&motorized);
+    let created = ImpulseJoint::from_mirror(&mirror, &()).unwrap();
+    assert_same_joint(&motorized.data, &created.data);
+}
+
+#[test]
+fn joint_parent_is_mapped() {
+    let mut harness = MirrorHarness::new();
+    harness.add_plugins(
+        RapierMirrorsPlugins
+            .build()
+            .set(ImpulseJointMirrorPlugin::new().create_source()),
+    );
+    let scene_parent = harness.spawn(());
+    let parent = harness.spawn(());
+    let revolute = joints(scene_parent).remove(0);
+    let entity = harness.spawn(ImpulseJointMirror::from(&revolute));
+
+    // As done when spawning a scene.
+    let registry = harness.world().resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let map_entities = registry
+        .get_type_data::<ReflectMapEntities>(TypeId::of::<ImpulseJointMirror>())
+        .unwrap();
+    let mut entity_map = HashMap::from([(scene_parent, parent)]);
+    map_entities.map_entities(harness.world_mut(), &mut entity_map, &[entity]);
+    harness.step();
+    assert_eq!(harness.get::<ImpulseJoint>(entity).parent, parent);
+}
+
+#[test]
+fn rapier_plugins() {
+    let mut harness = MirrorHarness::new();
+    harness.add_plugins(
+        RapierMirrorsPlugins
+            .build()
+            .set(ColliderMirrorPlugin::new().create_source()),
+    );
+    let collider = harness.spawn(Collider::ball(0.5));
+    let mirror_only = harness.spawn(ColliderMirror::from(&Collider::cuboid(1.0, 1.0, 1.0)));
+    harness.steps(2);
+
+    let roundtrip = MirrorRoundtrip::new();
+    let expected = ColliderMirror::from(harness.get::<Collider>(collider));
+    roundtrip.assert_eq(harness.get::<ColliderMirror>(collider), &expected);
+    let created = ColliderMirror::from(harness.get::<Collider>(mirror_only));
+    roundtrip.assert_eq(harness.get::<ColliderMirror>(mirror_only), &created);
+
+    *harness.get_mut::<ColliderMirror>(collider) = ColliderMirror::from(&Collider::cone(1.0, 2.0));
+    harness.step();
+    let edited = ColliderMirror::from(harness.get::<Collider>(collider));
+    roundtrip.assert_eq(&edited, &ColliderMirror::from(&Collider::cone(1.0, 2.0)));
+
+    let parent = harness.spawn(());
+    let joint = joints(parent).remove(0);
+    let jointed = harness.spawn(joint);
+    harness.steps(2);
+    let mirror = harness.get::<ImpulseJointMirror>(jointed);
+    roundtrip.assert_eq(mirror, &ImpulseJointMirror::from(&joints(parent).remove(0)));
+
+    harness.get_mut::<ColliderMirror>(collider).shape_rounded = Some(-1.0);
+    harness.step();
+    let errors = harness.drain_events::<MirrorError>();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].entity, Some(collider));
+}
+
+#[test]
+fn lost_shape_is_rejected() {
+    let halfspace = Collider::halfspace(Vec3::Y).unwrap();
+    let dynamic = ColliderMirror::from(&halfspace).clone_value();
+    let lost = ColliderMirror::from_reflect(&*dynamic).unwrap();
+
+    let mut ball = Collider::ball(0.5);
+    assert_eq!(lost.try_apply(&mut ball), Err(InvalidShape::Unimplemented));
+    assert_eq!(ball.as_ball().map(|ball| ball.radius()), Some(0.5));
+
+    let mut harness = MirrorHarness::new();
+    harness.add_plugins(
+        RapierMirrorsPlugins
+            .build()
+            .set(ColliderMirrorPlugin::new().create_source()),
+    );
+    let entity = harness.spawn(lost);
+    harness.steps(2);
+    assert!(!harness.has::<Collider>(entity));
+    let errors = harness.drain_events::<MirrorError>();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].entity, Some(entity));
+}